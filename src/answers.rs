@@ -0,0 +1,180 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use crate::input::has_fetched_input;
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Part};
+
+/// Known correct answers, keyed by day and part. Stored as a small TOML file:
+///
+/// ```toml
+/// [day_1]
+/// part_one = 11
+/// part_two = 31
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    recorded: BTreeMap<(u8, Part), String>,
+}
+
+/// The answers for the bundled inputs.
+pub fn default_answers_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("inputs")
+        .join("answers.toml")
+}
+
+/// The answers for the fetched inputs, kept next to them and ignored by git like them.
+pub fn fetched_answers_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("inputs")
+        .join("real")
+        .join("answers.toml")
+}
+
+/// The answers file that belongs to the input a day runs on by default.
+pub fn answers_path_for(day: u8) -> PathBuf {
+    if has_fetched_input(day) {
+        fetched_answers_path()
+    } else {
+        default_answers_path()
+    }
+}
+
+fn part_key(part: Part) -> &'static str {
+    match part {
+        Part::One => "part_one",
+        Part::Two => "part_two",
+    }
+}
+
+fn parse_value(value: Span) -> Result<String, ParseError> {
+    let text = value.text();
+    if text.len() >= 2 && text.starts_with('"') && text.ends_with('"') {
+        Ok(text[1..text.len() - 1].to_string())
+    } else {
+        value
+            .parse::<i64>("an integer or a quoted string")
+            .map(|number| number.to_string())
+    }
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut recorded = BTreeMap::new();
+        let mut day = None;
+
+        for line in Span::new(input).lines().map(|line| line.trim()) {
+            if line.is_empty() || line.text().starts_with('#') {
+                continue;
+            }
+
+            if line.text().starts_with('[') {
+                let (day_number, _) = line.strip_prefix("[day_")?.split_once("]", "`]`")?;
+                day = Some(day_number.parse("a day number")?);
+            } else {
+                let (key, value) = line.split_once("=", "`part_one = …` or `part_two = …`")?;
+                let part = match key.trim().text() {
+                    "part_one" => Part::One,
+                    "part_two" => Part::Two,
+                    _ => return Err(key.trim().error("`part_one` or `part_two`")),
+                };
+                let day = day.ok_or_else(|| line.error("a `[day_N]` header"))?;
+                recorded.insert((day, part), parse_value(value.trim())?);
+            }
+        }
+
+        Ok(Self { recorded })
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.recorded.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &Answer) {
+        self.recorded.insert((day, part), answer.to_string());
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = (u8, Part, &str)> {
+        self.recorded
+            .iter()
+            .map(|((day, part), answer)| (*day, *part, answer.as_str()))
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        let mut current_day = None;
+
+        for (day, part, answer) in self.entries() {
+            if current_day != Some(day) {
+                if current_day.is_some() {
+                    toml.push('\n');
+                }
+                writeln!(toml, "[day_{day}]").unwrap();
+                current_day = Some(day);
+            }
+            if answer.parse::<i64>().is_ok() {
+                writeln!(toml, "{} = {answer}", part_key(part)).unwrap();
+            } else {
+                writeln!(toml, "{} = \"{answer}\"", part_key(part)).unwrap();
+            }
+        }
+
+        toml
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse(
+            "# comment\n[day_1]\npart_one = 11\npart_two = -31\n\n[day_17]\npart_one = \"4,6,3\"\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("11"));
+        assert_eq!(answers.get(1, Part::Two), Some("-31"));
+        assert_eq!(answers.get(17, Part::One), Some("4,6,3"));
+        assert_eq!(answers.get(17, Part::Two), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_answers() {
+        assert_eq!(
+            Answers::parse("part_one = 1").unwrap_err().to_string(),
+            "line 1, column 1: expected a `[day_N]` header, found `part_one = 1`"
+        );
+        assert_eq!(
+            Answers::parse("[day_1]\npart_three = 1")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: expected `part_one` or `part_two`, found `part_three`"
+        );
+        assert_eq!(
+            Answers::parse("[day_1]\npart_one = eleven")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 12: expected an integer or a quoted string, found `eleven`"
+        );
+    }
+
+    #[test]
+    fn test_to_toml_round_trip() {
+        let mut answers = Answers::default();
+        answers.record(2, Part::Two, &Answer::Integer(4));
+        answers.record(1, Part::One, &Answer::Integer(11));
+        answers.record(2, Part::One, &Answer::Integer(2));
+
+        let toml = answers.to_toml();
+        assert_eq!(
+            toml,
+            "[day_1]\npart_one = 11\n\n[day_2]\npart_one = 2\npart_two = 4\n"
+        );
+        assert_eq!(Answers::parse(&toml), Ok(answers));
+    }
+}
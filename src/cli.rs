@@ -0,0 +1,838 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::SystemTime;
+
+use crate::answers::{answers_path_for, Answers};
+use crate::config::Config;
+use crate::days::{find_day, DAYS};
+use crate::fetch::{fetch_input, Fetched};
+use crate::input::{fetched_input_path, InputError, InputSource};
+use crate::report::{self, Format};
+use crate::runner::{measure_day, solve_day, RunError};
+use crate::solution::Part;
+use crate::submit::{default_history_path, submit_answer, History, Verdict};
+use crate::timing;
+use crate::verify::{format_example_table, format_table, verify_day, verify_example, Verification};
+
+const USAGE: &str = "Usage:
+  aoc run <DAY | FIRST..LAST | --all>... [--input <FILE | ->] [--bench <RUNS>]
+          [--format <text | json | plain>]
+  aoc run <DAY | FIRST..LAST | --all>... --example
+  aoc verify [--answers <FILE>] [--record]
+  aoc fetch <DAY | FIRST..LAST>... [--force]
+  aoc submit <DAY> <PART> [--input <FILE | ->]";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        days: Vec<u8>,
+        input_source: InputSource,
+        bench: Option<usize>,
+        format: Format,
+        example: bool,
+    },
+    Verify {
+        /// Checks every day against this file instead of the answers for the input it runs on.
+        answers_path: Option<PathBuf>,
+        record: bool,
+    },
+    Fetch {
+        days: Vec<u8>,
+        force: bool,
+    },
+    Submit {
+        day: u8,
+        part: Part,
+        input_source: InputSource,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CliError {
+    MissingCommand,
+    UnknownCommand(String),
+    MissingDays,
+    InvalidDays(String),
+    UnknownDay(u8),
+    MissingInputPath,
+    InputForMultipleDays,
+    MissingBenchRuns,
+    InvalidBenchRuns(String),
+    MissingFormat,
+    InvalidFormat(String),
+    ExampleWith(&'static str),
+    MissingPart,
+    InvalidPart(String),
+    NoPartTwo(u8),
+    MissingAnswersPath,
+    UnexpectedArgument(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingCommand => write!(f, "no command given"),
+            Self::UnknownCommand(command) => write!(f, "unknown command `{command}`"),
+            Self::MissingDays => write!(f, "no days given"),
+            Self::InvalidDays(days) => write!(f, "`{days}` is neither a day nor a range of days"),
+            Self::UnknownDay(day) => write!(f, "day {day} is not solved yet"),
+            Self::MissingInputPath => write!(f, "`--input` requires a file path or `-`"),
+            Self::InputForMultipleDays => {
+                write!(f, "`--input` can only be used when running a single day")
+            }
+            Self::MissingBenchRuns => write!(f, "`--bench` requires a number of runs"),
+            Self::InvalidBenchRuns(runs) => {
+                write!(f, "`{runs}` is not a positive number of runs")
+            }
+            Self::MissingFormat => write!(f, "`--format` requires `text`, `json` or `plain`"),
+            Self::InvalidFormat(format) => write!(f, "unknown output format `{format}`"),
+            Self::ExampleWith(option) => {
+                write!(f, "`--example` cannot be combined with `{option}`")
+            }
+            Self::MissingPart => write!(f, "no part given"),
+            Self::InvalidPart(part) => write!(f, "`{part}` is not a part, expected `1` or `2`"),
+            Self::NoPartTwo(day) => write!(f, "day {day} has no part two"),
+            Self::MissingAnswersPath => write!(f, "`--answers` requires a file path"),
+            Self::UnexpectedArgument(argument) => write!(f, "unexpected argument `{argument}`"),
+        }
+    }
+}
+
+fn parse_day(day: &str) -> Option<u8> {
+    day.parse().ok()
+}
+
+/// A single day or an inclusive range of days, whether solved or not.
+fn parse_day_range(days: &str) -> Result<Vec<u8>, CliError> {
+    if let Some((first, last)) = days.split_once("..") {
+        let last = last.strip_prefix('=').unwrap_or(last);
+        match (parse_day(first), parse_day(last)) {
+            (Some(first), Some(last)) if first <= last => Ok((first..=last).collect()),
+            _ => Err(CliError::InvalidDays(days.to_string())),
+        }
+    } else {
+        Ok(vec![
+            parse_day(days).ok_or_else(|| CliError::InvalidDays(days.to_string()))?
+        ])
+    }
+}
+
+fn parse_days(days: &str) -> Result<Vec<u8>, CliError> {
+    if days == "--all" {
+        return Ok(DAYS.iter().map(|day| day.number).collect());
+    }
+
+    let selected_days = parse_day_range(days)?;
+    match selected_days.iter().find(|day| find_day(**day).is_none()) {
+        Some(unknown_day) => Err(CliError::UnknownDay(*unknown_day)),
+        None => Ok(selected_days),
+    }
+}
+
+fn parse_bench_runs(runs: &str) -> Result<usize, CliError> {
+    match runs.parse() {
+        Ok(runs) if runs > 0 => Ok(runs),
+        _ => Err(CliError::InvalidBenchRuns(runs.to_string())),
+    }
+}
+
+pub fn parse_arguments<I, S>(arguments: I) -> Result<Command, CliError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut arguments = arguments.into_iter();
+    let command = arguments.next().ok_or(CliError::MissingCommand)?;
+
+    match command.as_ref() {
+        "run" => {
+            let mut days = Vec::new();
+            let mut input_source = InputSource::Default;
+            let mut bench = None;
+            let mut format = Format::Text;
+            let mut example = false;
+            while let Some(argument) = arguments.next() {
+                match argument.as_ref() {
+                    "--input" => {
+                        let path = arguments.next().ok_or(CliError::MissingInputPath)?;
+                        input_source = InputSource::from_argument(path.as_ref());
+                    }
+                    "--bench" => {
+                        let runs = arguments.next().ok_or(CliError::MissingBenchRuns)?;
+                        bench = Some(parse_bench_runs(runs.as_ref())?);
+                    }
+                    "--format" => {
+                        let name = arguments.next().ok_or(CliError::MissingFormat)?;
+                        format = Format::from_argument(name.as_ref())
+                            .ok_or_else(|| CliError::InvalidFormat(name.as_ref().to_string()))?;
+                    }
+                    "--example" => example = true,
+                    argument => days.extend(parse_days(argument)?),
+                }
+            }
+
+            if days.is_empty() {
+                Err(CliError::MissingDays)
+            } else if example && input_source != InputSource::Default {
+                Err(CliError::ExampleWith("--input"))
+            } else if example && bench.is_some() {
+                Err(CliError::ExampleWith("--bench"))
+            } else if example && format != Format::Text {
+                Err(CliError::ExampleWith("--format"))
+            } else if days.len() > 1 && input_source != InputSource::Default {
+                Err(CliError::InputForMultipleDays)
+            } else {
+                Ok(Command::Run {
+                    days,
+                    input_source,
+                    bench,
+                    format,
+                    example,
+                })
+            }
+        }
+        "verify" => {
+            let mut answers_path = None;
+            let mut record = false;
+            while let Some(argument) = arguments.next() {
+                match argument.as_ref() {
+                    "--answers" => {
+                        let path = arguments.next().ok_or(CliError::MissingAnswersPath)?;
+                        answers_path = Some(PathBuf::from(path.as_ref()));
+                    }
+                    "--record" => record = true,
+                    argument => return Err(CliError::UnexpectedArgument(argument.to_string())),
+                }
+            }
+
+            Ok(Command::Verify {
+                answers_path,
+                record,
+            })
+        }
+        "fetch" => {
+            let mut days = Vec::new();
+            let mut force = false;
+            for argument in arguments {
+                match argument.as_ref() {
+                    "--force" => force = true,
+                    argument => days.extend(parse_day_range(argument)?),
+                }
+            }
+
+            if days.is_empty() {
+                Err(CliError::MissingDays)
+            } else {
+                Ok(Command::Fetch { days, force })
+            }
+        }
+        "submit" => {
+            let day = arguments.next().ok_or(CliError::MissingDays)?;
+            let day = parse_day(day.as_ref())
+                .ok_or_else(|| CliError::InvalidDays(day.as_ref().to_string()))?;
+            let Some(solved_day) = find_day(day) else {
+                return Err(CliError::UnknownDay(day));
+            };
+            let part = match arguments.next().ok_or(CliError::MissingPart)?.as_ref() {
+                "1" => Part::One,
+                "2" => Part::Two,
+                part => return Err(CliError::InvalidPart(part.to_string())),
+            };
+            if !solved_day.parts().contains(&part) {
+                return Err(CliError::NoPartTwo(day));
+            }
+
+            let mut input_source = InputSource::Default;
+            while let Some(argument) = arguments.next() {
+                match argument.as_ref() {
+                    "--input" => {
+                        let path = arguments.next().ok_or(CliError::MissingInputPath)?;
+                        input_source = InputSource::from_argument(path.as_ref());
+                    }
+                    argument => return Err(CliError::UnexpectedArgument(argument.to_string())),
+                }
+            }
+
+            Ok(Command::Submit {
+                day,
+                part,
+                input_source,
+            })
+        }
+        command => Err(CliError::UnknownCommand(command.to_string())),
+    }
+}
+
+pub fn main<I, S>(arguments: I) -> ExitCode
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    match parse_arguments(arguments) {
+        Ok(Command::Run {
+            days,
+            input_source,
+            bench,
+            format,
+            example: false,
+        }) => run(&days, &input_source, bench, format),
+        Ok(Command::Run {
+            days,
+            example: true,
+            ..
+        }) => run_examples(&days),
+        Ok(Command::Verify {
+            answers_path,
+            record,
+        }) => verify(answers_path.as_deref(), record),
+        Ok(Command::Fetch { days, force }) => fetch(&days, force),
+        Ok(Command::Submit {
+            day,
+            part,
+            input_source,
+        }) => submit(day, part, &input_source),
+        Err(error) => {
+            eprintln!("error: {error}");
+            eprintln!("{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Points out days that run on a bundled input because theirs was not fetched, since the answers
+/// then belong to that input rather than the user's puzzle.
+fn note_bundled_input(day: u8, input_source: &InputSource) {
+    if let Some(path) = input_source.bundled_fallback(day) {
+        if path.is_file() {
+            eprintln!(
+                "note: day {day} has no fetched input, using the bundled {}",
+                path.display()
+            );
+        }
+    }
+}
+
+fn run(days: &[u8], input_source: &InputSource, bench: Option<usize>, format: Format) -> ExitCode {
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut timings = Vec::new();
+    let mut records = Vec::new();
+    for day in days.iter().flat_map(|day| find_day(*day)) {
+        note_bundled_input(day.number, input_source);
+        match measure_day(day, input_source, bench.unwrap_or(1)) {
+            Ok(measured) => {
+                match format {
+                    Format::Text => print!("{}", report::format_text(day.number, &measured)),
+                    Format::Plain => print!(
+                        "{}",
+                        report::format_plain(&report::records(day.number, &measured))
+                    ),
+                    Format::Json => records.extend(report::records(day.number, &measured)),
+                }
+                timings.push((day.number, measured.timings));
+            }
+            Err(error) => {
+                eprintln!("error: {error}");
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    match format {
+        Format::Text if bench.is_some() => {
+            println!();
+            print!("{}", timing::format_table(&timings));
+        }
+        Format::Json => print!("{}", report::format_json(&records)),
+        Format::Text | Format::Plain => {}
+    }
+    exit_code
+}
+
+fn run_examples(days: &[u8]) -> ExitCode {
+    let mut verifications = Vec::new();
+    for day in days.iter().flat_map(|day| find_day(*day)) {
+        if day.examples.is_empty() {
+            eprintln!("warning: day {} has no registered examples", day.number);
+        }
+        for example in day.examples {
+            let answers = day
+                .solve_example(example.input)
+                .map_err(|error| eprintln!("error: example `{}`: {error}", example.name))
+                .ok();
+            verifications.extend(verify_example(day.number, example, answers));
+        }
+    }
+    print!("{}", format_example_table(&verifications));
+
+    if verifications
+        .iter()
+        .any(|example| example.verification.status.is_failure())
+    {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn fetch(days: &[u8], force: bool) -> ExitCode {
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+    for &day in days {
+        let path = fetched_input_path(day);
+        match fetch_input(&config, day, &path, force, SystemTime::now()) {
+            Ok(Fetched::Downloaded) => println!("Day {day}: downloaded to {}", path.display()),
+            Ok(Fetched::Cached) => println!(
+                "Day {day}: already cached at {} (use --force to download again)",
+                path.display()
+            ),
+            Err(error) => {
+                eprintln!("error: {error}");
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+    exit_code
+}
+
+fn read_history(history_path: &Path) -> Result<History, String> {
+    match fs::read_to_string(history_path) {
+        Ok(history) => History::parse(&history).map_err(|error| {
+            format!(
+                "invalid attempt history {}: {error}",
+                history_path.display()
+            )
+        }),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+        Err(error) => Err(format!(
+            "could not read attempt history {}: {error}",
+            history_path.display()
+        )),
+    }
+}
+
+fn submit(day_number: u8, part: Part, input_source: &InputSource) -> ExitCode {
+    // The bundled inputs are examples, whose answers must not be posted for the user's puzzle.
+    if input_source.bundled_fallback(day_number).is_some() {
+        eprintln!(
+            "error: day {day_number} has no fetched input, fetch it with `aoc fetch {day_number}` \
+             or pass one with `--input`"
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let history_path = default_history_path();
+    let loaded = Config::load()
+        .map_err(|error| error.to_string())
+        .and_then(|config| Ok((config, read_history(&history_path)?)));
+    let (config, mut history) = match loaded {
+        Ok(loaded) => loaded,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let day = find_day(day_number).expect("submitted days are checked when parsing arguments");
+    let answer = match solve_day(day, input_source) {
+        Ok((part_one, part_two)) => match part {
+            Part::One => part_one,
+            Part::Two => part_two,
+        },
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(answer) = answer else {
+        eprintln!("error: the input has no answer for day {day_number}, part {part}");
+        return ExitCode::FAILURE;
+    };
+    let write_history = |history: &History| {
+        write_file(&history_path, &history.to_text()).map_err(|error| {
+            format!(
+                "could not write attempt history {}: {error}",
+                history_path.display()
+            )
+        })
+    };
+    // Make sure the history can be written before a verdict depends on it.
+    if let Err(error) = write_history(&history) {
+        eprintln!("error: {error}");
+        return ExitCode::FAILURE;
+    }
+    println!("Day {day_number}, part {part}: submitting {answer}");
+
+    let verdict = match submit_answer(&config, &mut history, day_number, part, &answer) {
+        Ok(verdict) => verdict,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(error) = write_history(&history) {
+        eprintln!("error: {error}, so the verdict was not remembered: {verdict}");
+        return ExitCode::FAILURE;
+    }
+    println!("{verdict}");
+
+    if verdict == Verdict::Correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn read_answers(answers_path: &Path) -> Result<Answers, String> {
+    match fs::read_to_string(answers_path) {
+        Ok(answers) => Answers::parse(&answers)
+            .map_err(|error| format!("invalid answers file {}: {error}", answers_path.display())),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(error) => Err(format!(
+            "could not read answers file {}: {error}",
+            answers_path.display()
+        )),
+    }
+}
+
+/// Writes the file, creating its directory first if needed.
+fn write_file(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    fs::write(path, contents)
+}
+
+/// Checks every day against the answers for the input it runs on: the fetched input's answers
+/// if it was fetched, the bundled input's otherwise. `answers_path` overrides this for all days.
+fn verify(answers_path: Option<&Path>, record: bool) -> ExitCode {
+    let day_answers_path =
+        |day| answers_path.map_or_else(|| answers_path_for(day), Path::to_path_buf);
+    let mut answer_files = BTreeMap::new();
+    for path in DAYS.iter().map(|day| day_answers_path(day.number)) {
+        if answer_files.contains_key(&path) {
+            continue;
+        }
+        match read_answers(&path) {
+            Ok(answers) => answer_files.insert(path, answers),
+            Err(error) => {
+                eprintln!("error: {error}");
+                return ExitCode::FAILURE;
+            }
+        };
+    }
+
+    let mut verifications = Vec::new();
+    for day in DAYS {
+        note_bundled_input(day.number, &InputSource::Default);
+        let answers = &answer_files[&day_answers_path(day.number)];
+        let solved_answers = match solve_day(day, &InputSource::Default) {
+            Ok(solved_answers) => Some(solved_answers),
+            // A day without any input can only be checked once it is fetched.
+            Err(RunError::Input(error @ InputError::NotFetched { .. }))
+                if !answers.entries().any(|(number, _, _)| number == day.number) =>
+            {
+                eprintln!("note: skipping, {error}");
+                continue;
+            }
+            Err(error) => {
+                eprintln!("error: {error}");
+                None
+            }
+        };
+        verifications.extend(verify_day(day.number, day.parts(), solved_answers, answers));
+    }
+    for answers in answer_files.values() {
+        verifications.extend(
+            answers
+                .entries()
+                .filter(|(day, _, _)| find_day(*day).is_none())
+                .map(|(day, part, expected)| Verification::new(day, part, None, Some(expected))),
+        );
+    }
+    print!("{}", format_table(&verifications));
+
+    if record {
+        let mut changed_paths = BTreeSet::new();
+        for verification in &verifications {
+            if let (None, Some(answer)) = (&verification.expected, &verification.answer) {
+                let path = day_answers_path(verification.day);
+                if let Some(answers) = answer_files.get_mut(&path) {
+                    answers.record(verification.day, verification.part, answer);
+                    changed_paths.insert(path);
+                }
+            }
+        }
+        for path in changed_paths {
+            if let Err(error) = write_file(&path, &answer_files[&path].to_toml()) {
+                eprintln!(
+                    "error: could not write answers file {}: {error}",
+                    path.display()
+                );
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if verifications
+        .iter()
+        .any(|verification| verification.status.is_failure())
+    {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_single_day() {
+        assert_eq!(
+            parse_arguments(["run", "5"]),
+            Ok(Command::Run {
+                days: vec![5],
+                input_source: InputSource::Default,
+                bench: None,
+                format: Format::Text,
+                example: false
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_day_ranges() {
+        assert_eq!(
+            parse_arguments(["run", "1..3"]),
+            Ok(Command::Run {
+                days: vec![1, 2, 3],
+                input_source: InputSource::Default,
+                bench: None,
+                format: Format::Text,
+                example: false
+            })
+        );
+        assert_eq!(
+            parse_arguments(["run", "4..=5", "7"]),
+            Ok(Command::Run {
+                days: vec![4, 5, 7],
+                input_source: InputSource::Default,
+                bench: None,
+                format: Format::Text,
+                example: false
+            })
+        );
+        assert_eq!(
+            parse_arguments(["run", "3..1"]),
+            Err(CliError::InvalidDays("3..1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_all_days() {
+        assert_eq!(
+            parse_arguments(["run", "--all"]),
+            Ok(Command::Run {
+                days: DAYS.iter().map(|day| day.number).collect(),
+                input_source: InputSource::Default,
+                bench: None,
+                format: Format::Text,
+                example: false
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_input_source() {
+        assert_eq!(
+            parse_arguments(["run", "3", "--input", "other.txt"]),
+            Ok(Command::Run {
+                days: vec![3],
+                input_source: InputSource::File("other.txt".into()),
+                bench: None,
+                format: Format::Text,
+                example: false
+            })
+        );
+        assert_eq!(
+            parse_arguments(["run", "--input", "-", "3"]),
+            Ok(Command::Run {
+                days: vec![3],
+                input_source: InputSource::Stdin,
+                bench: None,
+                format: Format::Text,
+                example: false
+            })
+        );
+        assert_eq!(
+            parse_arguments(["run", "3", "--input"]),
+            Err(CliError::MissingInputPath)
+        );
+        assert_eq!(
+            parse_arguments(["run", "1..2", "--input", "-"]),
+            Err(CliError::InputForMultipleDays)
+        );
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            parse_arguments(["run", "6..7", "--bench", "10"]),
+            Ok(Command::Run {
+                days: vec![6, 7],
+                input_source: InputSource::Default,
+                bench: Some(10),
+                format: Format::Text,
+                example: false
+            })
+        );
+        assert_eq!(
+            parse_arguments(["run", "6", "--bench"]),
+            Err(CliError::MissingBenchRuns)
+        );
+        assert_eq!(
+            parse_arguments(["run", "6", "--bench", "0"]),
+            Err(CliError::InvalidBenchRuns("0".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(
+            parse_arguments(["run", "1", "--format", "json"]),
+            Ok(Command::Run {
+                days: vec![1],
+                input_source: InputSource::Default,
+                bench: None,
+                format: Format::Json,
+                example: false
+            })
+        );
+        assert_eq!(
+            parse_arguments(["run", "1", "--format"]),
+            Err(CliError::MissingFormat)
+        );
+        assert_eq!(
+            parse_arguments(["run", "1", "--format", "yaml"]),
+            Err(CliError::InvalidFormat("yaml".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_example() {
+        assert_eq!(
+            parse_arguments(["run", "12", "--example"]),
+            Ok(Command::Run {
+                days: vec![12],
+                input_source: InputSource::Default,
+                bench: None,
+                format: Format::Text,
+                example: true
+            })
+        );
+        assert_eq!(
+            parse_arguments(["run", "12", "--example", "--input", "-"]),
+            Err(CliError::ExampleWith("--input"))
+        );
+        assert_eq!(
+            parse_arguments(["run", "12", "--example", "--bench", "3"]),
+            Err(CliError::ExampleWith("--bench"))
+        );
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        assert_eq!(
+            parse_arguments(["fetch", "14..16", "--force"]),
+            Ok(Command::Fetch {
+                days: vec![14, 15, 16],
+                force: true
+            })
+        );
+        assert_eq!(parse_arguments(["fetch"]), Err(CliError::MissingDays));
+    }
+
+    #[test]
+    fn test_parse_submit() {
+        assert_eq!(
+            parse_arguments(["submit", "7", "2", "--input", "-"]),
+            Ok(Command::Submit {
+                day: 7,
+                part: Part::Two,
+                input_source: InputSource::Stdin
+            })
+        );
+        assert_eq!(parse_arguments(["submit", "7"]), Err(CliError::MissingPart));
+        assert_eq!(
+            parse_arguments(["submit", "7", "3"]),
+            Err(CliError::InvalidPart("3".to_string()))
+        );
+        assert_eq!(
+            parse_arguments(["submit", "99", "1"]),
+            Err(CliError::UnknownDay(99))
+        );
+        assert_eq!(
+            parse_arguments(["submit", "25", "2"]),
+            Err(CliError::NoPartTwo(25))
+        );
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(
+            parse_arguments(["verify"]),
+            Ok(Command::Verify {
+                answers_path: None,
+                record: false
+            })
+        );
+        assert_eq!(
+            parse_arguments(["verify", "--record", "--answers", "answers.toml"]),
+            Ok(Command::Verify {
+                answers_path: Some(PathBuf::from("answers.toml")),
+                record: true
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_arguments() {
+        assert_eq!(
+            parse_arguments(Vec::<String>::new()),
+            Err(CliError::MissingCommand)
+        );
+        assert_eq!(
+            parse_arguments(["walk", "1"]),
+            Err(CliError::UnknownCommand("walk".to_string()))
+        );
+        assert_eq!(parse_arguments(["run"]), Err(CliError::MissingDays));
+        assert_eq!(
+            parse_arguments(["run", "one"]),
+            Err(CliError::InvalidDays("one".to_string()))
+        );
+        assert_eq!(
+            parse_arguments(["run", "99"]),
+            Err(CliError::UnknownDay(99))
+        );
+        assert_eq!(
+            parse_arguments(["verify", "--answers"]),
+            Err(CliError::MissingAnswersPath)
+        );
+        assert_eq!(
+            parse_arguments(["verify", "5"]),
+            Err(CliError::UnexpectedArgument("5".to_string()))
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt;
+use std::time::Duration;
+
+/// Identifies this tool to the Advent of Code servers, as their operator asks automated tools to do.
+pub const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (personal Advent of Code runner; ureq)"
+);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ClientError {
+    /// The server answered, but not with a success status.
+    Status { status: u16, body: String },
+    /// The server could not be reached or the connection broke.
+    Transport(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Status { status, body } => {
+                write!(f, "server responded with status {status}")?;
+                match body.lines().map(str::trim).find(|line| !line.is_empty()) {
+                    Some(line) => write!(f, ": {line}"),
+                    None => Ok(()),
+                }
+            }
+            Self::Transport(error) => write!(f, "request failed: {error}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Status(status, response) => Self::Status {
+                status,
+                body: response.into_string().unwrap_or_default(),
+            },
+            ureq::Error::Transport(transport) => Self::Transport(transport.to_string()),
+        }
+    }
+}
+
+/// An authenticated session with the Advent of Code website (or a server mimicking it).
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(&self.url(path))
+            .set("Cookie", &self.cookie())
+            .call()?;
+        response
+            .into_string()
+            .map_err(|error| ClientError::Transport(error.to_string()))
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .post(&self.url(path))
+            .set("Cookie", &self.cookie())
+            .send_form(form)?;
+        response
+            .into_string()
+            .map_err(|error| ClientError::Transport(error.to_string()))
+    }
+}
@@ -0,0 +1,165 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::parse::{ParseError, Span};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings for talking to the Advent of Code website. Read from a small TOML file:
+///
+/// ```toml
+/// session = "53616c7465645f5f…"
+/// base_url = "https://adventofcode.com"
+/// ```
+///
+/// The `AOC_SESSION` and `AOC_BASE_URL` environment variables take precedence over the file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Unreadable { path: PathBuf, error: io::Error },
+    Invalid { path: PathBuf, error: ParseError },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unreadable { path, error } => {
+                write!(f, "could not read config file {}: {error}", path.display())
+            }
+            Self::Invalid { path, error } => {
+                write!(f, "invalid config file {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+/// `AOC_CONFIG` if set, otherwise `aoc2024/config.toml` in the user's config directory.
+pub fn default_config_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("AOC_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+    let config_directory = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_directory.join("aoc2024").join("config.toml"))
+}
+
+fn parse_string(value: Span) -> Result<String, ParseError> {
+    let text = value.text();
+    if text.len() >= 2 && text.starts_with('"') && text.ends_with('"') {
+        Ok(text[1..text.len() - 1].to_string())
+    } else {
+        Err(value.error("a quoted string"))
+    }
+}
+
+impl Config {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut config = Self::default();
+
+        for line in Span::new(input).lines().map(|line| line.trim()) {
+            if line.is_empty() || line.text().starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line.split_once("=", "`key = \"value\"`")?;
+            let value = parse_string(value.trim())?;
+            match key.trim().text() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                _ => return Err(key.trim().error("`session` or `base_url`")),
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Reads the config file (a missing file is fine) and applies the environment overrides.
+    pub fn load() -> Result<Self, ConfigError> {
+        let config = match default_config_path() {
+            Some(path) => match fs::read_to_string(&path) {
+                Ok(config) => {
+                    Self::parse(&config).map_err(|error| ConfigError::Invalid { path, error })?
+                }
+                Err(error) if error.kind() == io::ErrorKind::NotFound => Self::default(),
+                Err(error) => return Err(ConfigError::Unreadable { path, error }),
+            },
+            None => Self::default(),
+        };
+
+        Ok(config.with_overrides(|name| env::var(name).ok()))
+    }
+
+    fn with_overrides(self, lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let non_empty = |name| lookup(name).filter(|value| !value.trim().is_empty());
+        Self {
+            session: non_empty("AOC_SESSION").or(self.session),
+            base_url: non_empty("AOC_BASE_URL").unwrap_or(self.base_url),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        assert_eq!(
+            Config::parse("# mine\nsession = \"abc123\"\n\nbase_url = \"http://localhost:8080\"\n"),
+            Ok(Config {
+                session: Some("abc123".to_string()),
+                base_url: "http://localhost:8080".to_string()
+            })
+        );
+        assert_eq!(Config::parse(""), Ok(Config::default()));
+    }
+
+    #[test]
+    fn test_parse_invalid_config() {
+        assert_eq!(
+            Config::parse("session = abc").unwrap_err().to_string(),
+            "line 1, column 11: expected a quoted string, found `abc`"
+        );
+        assert_eq!(
+            Config::parse("token = \"abc\"").unwrap_err().to_string(),
+            "line 1, column 1: expected `session` or `base_url`, found `token`"
+        );
+    }
+
+    #[test]
+    fn test_environment_overrides() {
+        let config = Config::parse("session = \"from file\"").unwrap();
+        assert_eq!(
+            config.clone().with_overrides(|name| match name {
+                "AOC_SESSION" => Some("from env".to_string()),
+                _ => None,
+            }),
+            Config {
+                session: Some("from env".to_string()),
+                base_url: DEFAULT_BASE_URL.to_string()
+            }
+        );
+        assert_eq!(
+            config.clone().with_overrides(|_| Some(" ".to_string())),
+            config
+        );
+    }
+}
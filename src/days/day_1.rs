@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Example, Solution};
+
+fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|line| line.split_once("   ", "two location IDs separated by three spaces"))
+        .map(|ids| {
+            ids.and_then(|(left, right)| {
+                Ok((
+                    left.parse::<i32>("a location ID")?,
+                    right.parse::<i32>("a location ID")?,
+                ))
+            })
+        })
+        .collect()
+}
+
+fn calculate_distances(left: &[i32], right: &[i32]) -> Vec<i32> {
+    let mut left = left.to_vec();
+    left.sort();
+    let mut right = right.to_vec();
+    right.sort();
+
+    left.iter().zip(right).map(|(x, y)| (x - y).abs()).collect()
+}
+
+fn calculate_similarities(left: &[i32], right: &[i32]) -> Vec<i32> {
+    let mut right_occurrences = HashMap::new();
+    for r in right {
+        *right_occurrences.entry(r).or_insert(0) += 1;
+    }
+
+    left.iter()
+        .map(|l| l * right_occurrences.get(l).unwrap_or(&0))
+        .collect()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<i32>, Vec<i32>);
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: include_str!("../inputs/examples/day_1.txt"),
+        part_one: Some("11"),
+        part_two: Some("31"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((left, right): &Self::Input) -> Option<Answer> {
+        Some(calculate_distances(left, right).iter().sum::<i32>().into())
+    }
+
+    fn part_two((left, right): &Self::Input) -> Option<Answer> {
+        Some(
+            calculate_similarities(left, right)
+                .iter()
+                .sum::<i32>()
+                .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            parse_input("3   4\r\n4   3\r\n"),
+            Ok((vec![3, 4], vec![4, 3]))
+        );
+        assert_eq!(
+            parse_input("3   4\n\n4   3").unwrap_err().to_string(),
+            "line 2, column 1: expected two location IDs separated by three spaces, found end of line"
+        );
+    }
+
+    fn example() -> (Vec<i32>, Vec<i32>) {
+        parse_input(Day1::EXAMPLES[0].input).unwrap()
+    }
+
+    #[test]
+    fn test_calculate_distances() {
+        let (left, right) = example();
+        assert_eq!(calculate_distances(&left, &right), vec![2, 1, 0, 1, 2, 5]);
+    }
+
+    #[test]
+    fn test_calculate_similarities() {
+        let (left, right) = example();
+        assert_eq!(
+            calculate_similarities(&left, &right),
+            vec![9, 4, 0, 0, 9, 9]
+        );
+    }
+}
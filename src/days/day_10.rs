@@ -0,0 +1,162 @@
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::search::{self, Search};
+use crate::solution::{Answer, Example, Solution};
+
+fn parse_input(input_data: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input_data, "a height digit", |c| c.to_digit(10))
+}
+
+fn find_start_points(map: &Grid<u32>) -> Vec<(usize, usize)> {
+    map.iter()
+        .filter(|(_, height)| **height == 0)
+        .map(|(point, _)| point)
+        .collect()
+}
+
+/// Every position reachable from `trailhead` by climbing exactly one height per step. Every step
+/// climbs, so all trails to a position have the same length and are shortest paths of the search.
+fn explore_trails(
+    trailhead: (usize, usize),
+    topographic_map: &Grid<u32>,
+) -> Search<(usize, usize), usize> {
+    search::bfs(
+        [trailhead],
+        |&position| {
+            let height = topographic_map[position];
+            topographic_map
+                .neighbours_4(position)
+                .filter(move |&neighbour| topographic_map[neighbour] == height + 1)
+        },
+        |_| false,
+    )
+}
+
+fn find_peaks<'a>(
+    trails: &'a Search<(usize, usize), usize>,
+    topographic_map: &'a Grid<u32>,
+) -> impl Iterator<Item = &'a (usize, usize)> {
+    trails
+        .costs()
+        .keys()
+        .filter(|&&position| topographic_map[position] == 9)
+}
+
+/// The number of peaks the trailhead reaches.
+fn get_trailhead_score(trailhead: (usize, usize), topographic_map: &Grid<u32>) -> usize {
+    let trails = explore_trails(trailhead, topographic_map);
+    find_peaks(&trails, topographic_map).count()
+}
+
+/// The number of distinct trails from the trailhead to any peak.
+fn get_trailhead_rating(trailhead: (usize, usize), topographic_map: &Grid<u32>) -> usize {
+    let trails = explore_trails(trailhead, topographic_map);
+    find_peaks(&trails, topographic_map)
+        .map(|peak| trails.count_paths(peak))
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Grid<u32>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "small",
+            input: include_str!("../inputs/examples/day_10_small.txt"),
+            part_one: Some("1"),
+            part_two: None,
+        },
+        Example {
+            name: "larger",
+            input: include_str!("../inputs/examples/day_10.txt"),
+            part_one: Some("36"),
+            part_two: Some("81"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(topographic_map: &Self::Input) -> Option<Answer> {
+        Some(
+            find_start_points(topographic_map)
+                .iter()
+                .map(|&trailhead| get_trailhead_score(trailhead, topographic_map))
+                .sum::<usize>()
+                .into(),
+        )
+    }
+
+    fn part_two(topographic_map: &Self::Input) -> Option<Answer> {
+        Some(
+            find_start_points(topographic_map)
+                .iter()
+                .map(|&trailhead| get_trailhead_rating(trailhead, topographic_map))
+                .sum::<usize>()
+                .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_start_points() {
+        let topographic_map = Grid::from_rows(vec![
+            vec![0, 1, 2, 3],
+            vec![1, 3, 5, 4],
+            vec![9, 0, 6, 9],
+            vec![9, 8, 7, 8],
+        ])
+        .unwrap();
+        assert_eq!(find_start_points(&topographic_map), vec![(0, 0), (1, 2),]);
+    }
+
+    #[test]
+    fn test_explore_trails() {
+        let topographic_map = Grid::from_rows(vec![
+            vec![0, 1, 2, 3],
+            vec![1, 3, 5, 4],
+            vec![9, 0, 6, 9],
+            vec![9, 8, 7, 8],
+        ])
+        .unwrap();
+        let trails = explore_trails((0, 0), &topographic_map);
+        assert_eq!(trails.cost(&(3, 2)), Some(9));
+        assert_eq!(trails.cost(&(0, 2)), None);
+        assert_eq!(
+            trails.path(&(0, 3)),
+            Some(vec![
+                (0, 0),
+                (1, 0),
+                (2, 0),
+                (3, 0),
+                (3, 1),
+                (2, 1),
+                (2, 2),
+                (2, 3),
+                (1, 3),
+                (0, 3)
+            ])
+        );
+        assert_eq!(get_trailhead_score((0, 0), &topographic_map), 2);
+        assert_eq!(get_trailhead_rating((0, 0), &topographic_map), 2);
+        assert_eq!(get_trailhead_score((1, 2), &topographic_map), 0);
+    }
+
+    #[test]
+    fn test_get_trailhead_rating() {
+        // The height is `x + y`, so every trail to the only peak climbs right or down.
+        let topographic_map =
+            Grid::from_rows((0..6).map(|y| (0..5).map(|x| x + y).collect()).collect()).unwrap();
+        assert_eq!(get_trailhead_score((0, 0), &topographic_map), 1);
+        assert_eq!(get_trailhead_rating((0, 0), &topographic_map), 126);
+    }
+}
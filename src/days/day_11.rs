@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Example, Solution};
+
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    Span::new(input)
+        .split_whitespace()
+        .map(|i| i.parse("a stone number"))
+        .collect()
+}
+
+fn change_stone(stone: u64) -> Vec<u64> {
+    let s = format!("{stone}");
+
+    if stone == 0 {
+        vec![1]
+    } else if s.len() % 2 == 0 {
+        let center = s.len() / 2;
+        vec![s[..center].parse().unwrap(), s[center..].parse().unwrap()]
+    } else {
+        vec![stone * 2024]
+    }
+}
+
+fn execute_blink(stones: &[u64]) -> Vec<u64> {
+    stones
+        .iter()
+        .flat_map(|stone| change_stone(*stone))
+        .collect()
+}
+
+fn observe_stone_counts_of_stone(
+    stones: &[u64],
+    number_of_blinks: u8,
+    memoization_cache: HashMap<(u64, u8), usize>,
+) -> (usize, HashMap<(u64, u8), usize>) {
+    let stones_after_blink = execute_blink(stones);
+
+    if number_of_blinks == 1 {
+        (stones_after_blink.len(), memoization_cache)
+    } else {
+        stones_after_blink
+            .iter()
+            .fold((0, memoization_cache), |(acc, cache), stone| {
+                if cache.contains_key(&(*stone, number_of_blinks - 1)) {
+                    let cached_result = *cache.get(&(*stone, number_of_blinks - 1)).unwrap();
+                    (acc + cached_result, cache)
+                } else {
+                    let (new_stones, updated_cache) = observe_stone_counts_of_stone(
+                        &[*stone],
+                        number_of_blinks - 1,
+                        cache.clone(),
+                    );
+                    (
+                        acc + new_stones,
+                        cache
+                            .into_iter()
+                            .chain(updated_cache)
+                            .chain([((*stone, number_of_blinks - 1), new_stones)])
+                            .collect(),
+                    )
+                }
+            })
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: include_str!("../inputs/examples/day_11.txt"),
+        part_one: Some("55312"),
+        part_two: None,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(initial_stones: &Self::Input) -> Option<Answer> {
+        Some(
+            observe_stone_counts_of_stone(initial_stones, 25, HashMap::new())
+                .0
+                .into(),
+        )
+    }
+
+    fn part_two(initial_stones: &Self::Input) -> Option<Answer> {
+        Some(
+            observe_stone_counts_of_stone(initial_stones, 75, HashMap::new())
+                .0
+                .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_change_stone() {
+        assert_eq!(change_stone(0), vec![1]);
+        assert_eq!(change_stone(1000), vec![10, 0]);
+        assert_eq!(change_stone(2), vec![4048]);
+    }
+
+    #[test]
+    fn test_execute_blink() {
+        assert_eq!(
+            execute_blink(&[0, 1, 10, 99, 999]),
+            vec![1, 2024, 1, 0, 9, 9, 2021976]
+        );
+    }
+
+    #[test]
+    fn test_observe_stone_counts_of_stone() {
+        let (stones, memoization_cache) =
+            observe_stone_counts_of_stone(&[125, 17], 6, HashMap::new());
+        assert_eq!(stones, 22);
+
+        assert_eq!(
+            observe_stone_counts_of_stone(&[125, 17], 25, memoization_cache).0,
+            55312
+        );
+    }
+}
@@ -0,0 +1,453 @@
+use std::collections::HashSet;
+
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::point::Direction;
+use crate::search;
+use crate::solution::{Answer, Example, Solution};
+
+pub struct Farm {
+    plots: Grid<char>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct FarmPlotInfo {
+    plot_type: char,
+    neighbor_north: Option<char>,
+    neighbor_south: Option<char>,
+    neighbor_west: Option<char>,
+    neighbor_east: Option<char>,
+}
+
+impl Farm {
+    fn get_neighbor(&self, x: usize, y: usize, direction: Direction) -> Option<char> {
+        self.plots
+            .step((x, y), direction)
+            .map(|neighbor| self.plots[neighbor])
+    }
+
+    fn get_plot_info(&self, x: usize, y: usize) -> FarmPlotInfo {
+        FarmPlotInfo {
+            plot_type: self.plots[(x, y)],
+            neighbor_north: self.get_neighbor(x, y, Direction::Up),
+            neighbor_south: self.get_neighbor(x, y, Direction::Down),
+            neighbor_west: self.get_neighbor(x, y, Direction::Left),
+            neighbor_east: self.get_neighbor(x, y, Direction::Right),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct FarmPlotRegion {
+    plot_type: char,
+    perimeter: u32,
+    area: u32,
+    plot_coordinates: HashSet<(usize, usize)>,
+    frontier_north: HashSet<(usize, usize)>,
+    frontier_south: HashSet<(usize, usize)>,
+    frontier_west: HashSet<(usize, usize)>,
+    frontier_east: HashSet<(usize, usize)>,
+}
+
+fn parse_input(input: &str) -> Result<Farm, ParseError> {
+    Ok(Farm {
+        plots: Grid::parse(input, "a plot type letter", |plot_type| {
+            plot_type.is_ascii_alphabetic().then_some(plot_type)
+        })?,
+    })
+}
+
+/// The plots of the region around `start`: every plot of the same type that can be reached
+/// through neighbouring plots of that type.
+fn flood_region(start: (usize, usize), farm: &Farm) -> HashSet<(usize, usize)> {
+    let plot_type = farm.plots[start];
+    search::bfs(
+        [start],
+        |&plot| {
+            farm.plots
+                .neighbours_4(plot)
+                .filter(move |&neighbour| farm.plots[neighbour] == plot_type)
+        },
+        |_| false,
+    )
+    .costs()
+    .keys()
+    .copied()
+    .collect()
+}
+
+/// The plots of the region whose neighbour in `direction` is outside of it, so that they need a
+/// fence on that side.
+fn region_frontier(
+    region: &HashSet<(usize, usize)>,
+    farm: &Farm,
+    direction: Direction,
+) -> HashSet<(usize, usize)> {
+    region
+        .iter()
+        .filter(|&&plot| {
+            farm.plots
+                .step(plot, direction)
+                .is_none_or(|neighbour| !region.contains(&neighbour))
+        })
+        .copied()
+        .collect()
+}
+
+fn calculate_fencing_perimeter(plot_info: &FarmPlotInfo) -> u32 {
+    [
+        plot_info.neighbor_north,
+        plot_info.neighbor_south,
+        plot_info.neighbor_west,
+        plot_info.neighbor_east,
+    ]
+    .iter()
+    .map(|p| {
+        p.map(|c| if c != plot_info.plot_type { 1 } else { 0 })
+            .unwrap_or(1)
+    })
+    .sum()
+}
+
+fn create_farm_plot_region_from_coordinates(
+    plot_type: char,
+    coordinates: HashSet<(usize, usize)>,
+    frontier_north: HashSet<(usize, usize)>,
+    frontier_south: HashSet<(usize, usize)>,
+    frontier_west: HashSet<(usize, usize)>,
+    frontier_east: HashSet<(usize, usize)>,
+    farm: &Farm,
+) -> FarmPlotRegion {
+    let perimeter = coordinates
+        .iter()
+        .map(|(x, y)| calculate_fencing_perimeter(&farm.get_plot_info(*x, *y)))
+        .sum();
+
+    FarmPlotRegion {
+        plot_type,
+        perimeter,
+        frontier_north,
+        frontier_south,
+        frontier_west,
+        frontier_east,
+        area: coordinates.len() as u32,
+        plot_coordinates: coordinates,
+    }
+}
+
+fn find_farm_plot_regions(farm: &Farm) -> Vec<FarmPlotRegion> {
+    let mut regions = Vec::new();
+
+    let mut covered_coordinates = HashSet::new();
+    for (x, y) in farm.plots.positions() {
+        if !covered_coordinates.contains(&(x, y)) {
+            let region_coordinates = flood_region((x, y), farm);
+            let frontier = |direction| region_frontier(&region_coordinates, farm, direction);
+            let region = create_farm_plot_region_from_coordinates(
+                farm.plots[(x, y)],
+                region_coordinates.clone(),
+                frontier(Direction::Up),
+                frontier(Direction::Down),
+                frontier(Direction::Left),
+                frontier(Direction::Right),
+                farm,
+            );
+
+            covered_coordinates.extend(region_coordinates);
+            regions.push(region);
+        }
+    }
+
+    regions
+}
+
+/// The frontier plots that touch `(x, y)` in any direction.
+fn frontier_neighbours(
+    (x, y): (usize, usize),
+    all_frontier_coordinates: &HashSet<(usize, usize)>,
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    [
+        y.checked_sub(1).map(|y| (x, y)),
+        Some((x, y + 1)),
+        x.checked_sub(1).map(|x| (x, y)),
+        Some((x + 1, y)),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|neighbour| all_frontier_coordinates.contains(neighbour))
+}
+
+/// Every connected run of frontier plots is one straight side of the fence.
+fn count_unique_sides_of_frontier(all_frontier_coordinates: &HashSet<(usize, usize)>) -> u32 {
+    let mut sides_count = 0;
+
+    let mut covered_coordinates = HashSet::new();
+    for coordinates in all_frontier_coordinates {
+        if !covered_coordinates.contains(coordinates) {
+            let side = search::bfs(
+                [*coordinates],
+                |&plot| frontier_neighbours(plot, all_frontier_coordinates),
+                |_| false,
+            );
+            covered_coordinates.extend(side.costs().keys().copied());
+            sides_count += 1;
+        }
+    }
+
+    sides_count
+}
+
+fn calculate_number_of_sides(farm_plot_region: &FarmPlotRegion) -> u32 {
+    count_unique_sides_of_frontier(&farm_plot_region.frontier_north)
+        + count_unique_sides_of_frontier(&farm_plot_region.frontier_south)
+        + count_unique_sides_of_frontier(&farm_plot_region.frontier_west)
+        + count_unique_sides_of_frontier(&farm_plot_region.frontier_east)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Farm;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "small",
+            input: include_str!("../inputs/examples/day_12_small.txt"),
+            part_one: Some("140"),
+            part_two: Some("80"),
+        },
+        Example {
+            name: "nested",
+            input: include_str!("../inputs/examples/day_12_nested.txt"),
+            part_one: Some("772"),
+            part_two: Some("436"),
+        },
+        Example {
+            name: "larger",
+            input: include_str!("../inputs/examples/day_12.txt"),
+            part_one: Some("1930"),
+            part_two: Some("1206"),
+        },
+        Example {
+            name: "E-shaped",
+            input: include_str!("../inputs/examples/day_12_e_shape.txt"),
+            part_one: None,
+            part_two: Some("236"),
+        },
+        Example {
+            name: "diagonal",
+            input: include_str!("../inputs/examples/day_12_diagonal.txt"),
+            part_one: None,
+            part_two: Some("368"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(farm: &Self::Input) -> Option<Answer> {
+        Some(
+            find_farm_plot_regions(farm)
+                .iter()
+                .map(|r| r.area * r.perimeter)
+                .sum::<u32>()
+                .into(),
+        )
+    }
+
+    fn part_two(farm: &Self::Input) -> Option<Answer> {
+        Some(
+            find_farm_plot_regions(farm)
+                .iter()
+                .map(|r| r.area * calculate_number_of_sides(r))
+                .sum::<u32>()
+                .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_neighboring_plots() {
+        let farm = Farm {
+            plots: Grid::from_rows(vec![
+                vec!['a', 'b', 'c'],
+                vec!['d', 'e', 'f'],
+                vec!['g', 'h', 'i'],
+            ])
+            .unwrap(),
+        };
+        assert_eq!(
+            farm.get_plot_info(0, 0),
+            FarmPlotInfo {
+                plot_type: 'a',
+                neighbor_north: None,
+                neighbor_south: Some('d'),
+                neighbor_west: None,
+                neighbor_east: Some('b'),
+            }
+        );
+        assert_eq!(
+            farm.get_plot_info(1, 1),
+            FarmPlotInfo {
+                plot_type: 'e',
+                neighbor_north: Some('b'),
+                neighbor_south: Some('h'),
+                neighbor_west: Some('d'),
+                neighbor_east: Some('f'),
+            }
+        );
+        assert_eq!(
+            farm.get_plot_info(2, 2),
+            FarmPlotInfo {
+                plot_type: 'i',
+                neighbor_north: Some('f'),
+                neighbor_south: None,
+                neighbor_west: Some('h'),
+                neighbor_east: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_flood_region() {
+        let farm = Farm {
+            plots: Grid::from_rows(vec![
+                vec!['a', 'b', 'b'],
+                vec!['b', 'b', 'b'],
+                vec!['a', 'b', 'c'],
+            ])
+            .unwrap(),
+        };
+        let region = flood_region((1, 0), &farm);
+        assert_eq!(
+            region,
+            HashSet::from([(1, 0), (2, 0), (0, 1), (1, 1), (2, 1), (1, 2),])
+        );
+        assert_eq!(flood_region((0, 2), &farm), HashSet::from([(0, 2)]));
+
+        let frontier = |direction| region_frontier(&region, &farm, direction);
+        assert_eq!(
+            frontier(Direction::Up),
+            HashSet::from([(0, 1), (1, 0), (2, 0)])
+        );
+        assert_eq!(
+            frontier(Direction::Down),
+            HashSet::from([(0, 1), (1, 2), (2, 1)])
+        );
+        assert_eq!(
+            frontier(Direction::Left),
+            HashSet::from([(0, 1), (1, 0), (1, 2)])
+        );
+        assert_eq!(
+            frontier(Direction::Right),
+            HashSet::from([(2, 0), (2, 1), (1, 2)])
+        );
+    }
+
+    #[test]
+    fn test_calculate_fencing_perimeter() {
+        let plot_info = FarmPlotInfo {
+            plot_type: 'a',
+            neighbor_north: Some('a'),
+            neighbor_south: Some('a'),
+            neighbor_west: Some('a'),
+            neighbor_east: Some('a'),
+        };
+        assert_eq!(calculate_fencing_perimeter(&plot_info), 0);
+
+        let plot_info = FarmPlotInfo {
+            plot_type: 'a',
+            neighbor_north: None,
+            neighbor_south: Some('a'),
+            neighbor_west: None,
+            neighbor_east: Some('b'),
+        };
+        assert_eq!(calculate_fencing_perimeter(&plot_info), 3);
+    }
+
+    #[test]
+    fn test_find_farm_plot_regions() {
+        let farm = Farm {
+            plots: Grid::from_rows(vec![
+                vec!['a', 'b', 'b'],
+                vec!['b', 'b', 'b'],
+                vec!['a', 'b', 'c'],
+            ])
+            .unwrap(),
+        };
+        assert_eq!(
+            find_farm_plot_regions(&farm),
+            vec![
+                FarmPlotRegion {
+                    plot_type: 'a',
+                    perimeter: 4,
+                    area: 1,
+                    plot_coordinates: HashSet::from([(0, 0)]),
+                    frontier_north: HashSet::from([(0, 0)]),
+                    frontier_south: HashSet::from([(0, 0)]),
+                    frontier_west: HashSet::from([(0, 0)]),
+                    frontier_east: HashSet::from([(0, 0)])
+                },
+                FarmPlotRegion {
+                    plot_type: 'b',
+                    perimeter: 12,
+                    area: 6,
+                    plot_coordinates: HashSet::from([
+                        (1, 0),
+                        (2, 0),
+                        (0, 1),
+                        (1, 1),
+                        (2, 1),
+                        (1, 2),
+                    ]),
+                    frontier_north: HashSet::from([(0, 1), (1, 0), (2, 0)]),
+                    frontier_south: HashSet::from([(0, 1), (1, 2), (2, 1)]),
+                    frontier_west: HashSet::from([(0, 1), (1, 0), (1, 2)]),
+                    frontier_east: HashSet::from([(2, 0), (2, 1), (1, 2)])
+                },
+                FarmPlotRegion {
+                    plot_type: 'a',
+                    perimeter: 4,
+                    area: 1,
+                    plot_coordinates: HashSet::from([(0, 2)]),
+                    frontier_north: HashSet::from([(0, 2)]),
+                    frontier_south: HashSet::from([(0, 2)]),
+                    frontier_west: HashSet::from([(0, 2)]),
+                    frontier_east: HashSet::from([(0, 2)])
+                },
+                FarmPlotRegion {
+                    plot_type: 'c',
+                    perimeter: 4,
+                    area: 1,
+                    plot_coordinates: HashSet::from([(2, 2)]),
+                    frontier_north: HashSet::from([(2, 2)]),
+                    frontier_south: HashSet::from([(2, 2)]),
+                    frontier_west: HashSet::from([(2, 2)]),
+                    frontier_east: HashSet::from([(2, 2)])
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn find_count_unique_sides_of_frontier() {
+        let frontier_coordinates = HashSet::from([
+            (0, 1),
+            (1, 1),
+            (2, 0),
+            (3, 1),
+            (4, 1),
+            (5, 1),
+            (6, 1),
+            (6, 3),
+        ]);
+        assert_eq!(count_unique_sides_of_frontier(&frontier_coordinates), 4);
+    }
+}
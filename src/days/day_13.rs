@@ -0,0 +1,318 @@
+use std::cmp::Ordering;
+
+use crate::fraction::Fraction;
+use crate::matrix::{Matrix, Solutions};
+use crate::number_theory::{ceil_div, floor_div, solve_linear_diophantine, LinearSolutions};
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Example, Solution};
+
+#[derive(Debug, Clone)]
+pub struct ClawMachine {
+    button_a_movement: (i64, i64),
+    button_b_movement: (i64, i64),
+    prize_position: (i64, i64),
+}
+
+impl ClawMachine {
+    fn from_input_block(input: Span) -> Result<Self, ParseError> {
+        let mut lines = input.lines();
+        let mut next_line = |prefix: &str| {
+            lines
+                .next()
+                .ok_or_else(|| input.end().error(&format!("`{prefix}`")))
+                .and_then(|line| line.strip_prefix(prefix))
+        };
+        let button_a_movement = parse_movement(next_line("Button A: ")?, '+')?;
+        let button_b_movement = parse_movement(next_line("Button B: ")?, '+')?;
+        let prize_position = parse_movement(next_line("Prize: ")?, '=')?;
+
+        Ok(Self {
+            button_a_movement,
+            button_b_movement,
+            prize_position,
+        })
+    }
+}
+
+fn parse_movement(s: Span, separator: char) -> Result<(i64, i64), ParseError> {
+    let (l, r) = s.split_once(", ", "X and Y separated by `, `")?;
+    Ok((
+        l.strip_prefix(&format!("X{separator}"))?
+            .parse("an X value")?,
+        r.strip_prefix(&format!("Y{separator}"))?
+            .parse("a Y value")?,
+    ))
+}
+
+fn parse_input(input: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    Span::new(input)
+        .blocks()
+        .into_iter()
+        .map(ClawMachine::from_input_block)
+        .collect()
+}
+
+/// The presses of A and B that move the claw to the prize, as exact fractions: a unique
+/// solution, none at all, or a whole line of them when the buttons move in the same direction.
+fn solve_presses(claw_machine: &ClawMachine) -> Solutions {
+    let (x_a, y_a) = claw_machine.button_a_movement;
+    let (x_b, y_b) = claw_machine.button_b_movement;
+    let (x_p, y_p) = claw_machine.prize_position;
+
+    let buttons = Matrix::from_rows(vec![
+        vec![x_a.into(), x_b.into()],
+        vec![y_a.into(), y_b.into()],
+    ]);
+    buttons.solve(&[x_p.into(), y_p.into()])
+}
+
+/// The cheapest whole, non-negative numbers of presses of A and B that move the claw to the
+/// prize, if there are any.
+fn find_button_press_combination(claw_machine: &ClawMachine) -> Option<(i64, i64)> {
+    match solve_presses(claw_machine) {
+        Solutions::None => None,
+        Solutions::Unique(presses) => {
+            let whole = |presses: Fraction| i64::try_from(presses.to_integer()?).ok();
+            let (a, b) = (whole(presses[0])?, whole(presses[1])?);
+            (a >= 0 && b >= 0).then_some((a, b))
+        }
+        Solutions::Infinite { .. } => {
+            // Both buttons move along the same line as the prize, so only one of the two
+            // equations constrains the presses.
+            let (x_a, y_a) = claw_machine.button_a_movement;
+            let (x_b, y_b) = claw_machine.button_b_movement;
+            let (x_p, y_p) = claw_machine.prize_position;
+            let solutions = if (x_a, x_b) != (0, 0) {
+                solve_linear_diophantine(x_a, x_b, x_p)
+            } else {
+                solve_linear_diophantine(y_a, y_b, y_p)
+            };
+            find_cheapest_solution(solutions)
+        }
+    }
+}
+
+/// The solution with non-negative presses that costs the fewest tokens. The cost changes linearly
+/// along a line of solutions, so the cheapest one lies at one end of its non-negative stretch.
+fn find_cheapest_solution(solutions: LinearSolutions) -> Option<(i64, i64)> {
+    let (a, b, step_a, step_b) = match solutions {
+        LinearSolutions::None => return None,
+        LinearSolutions::All => return Some((0, 0)),
+        LinearSolutions::Line {
+            x,
+            y,
+            step_x,
+            step_y,
+        } => (x, y, step_x, step_y),
+    };
+
+    // The range of `k` for which `presses + k * step` is non-negative for both buttons.
+    let (mut lowest, mut highest) = (i64::MIN, i64::MAX);
+    for (presses, step) in [(a, step_a), (b, step_b)] {
+        match step.cmp(&0) {
+            Ordering::Greater => lowest = lowest.max(ceil_div(-presses, step)),
+            Ordering::Less => highest = highest.min(floor_div(-presses, step)),
+            Ordering::Equal if presses < 0 => return None,
+            Ordering::Equal => {}
+        }
+    }
+    if lowest > highest {
+        return None;
+    }
+
+    let k = if calculate_token_cost(step_a, step_b) > 0 {
+        lowest
+    } else {
+        highest
+    };
+    Some((a + k * step_a, b + k * step_b))
+}
+
+fn calculate_token_cost(a: i64, b: i64) -> i64 {
+    a * 3 + b
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<ClawMachine>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: include_str!("../inputs/examples/day_13.txt"),
+        part_one: Some("480"),
+        part_two: None,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(claw_machines: &Self::Input) -> Option<Answer> {
+        Some(
+            claw_machines
+                .iter()
+                .flat_map(find_button_press_combination)
+                .map(|(a, b)| calculate_token_cost(a, b))
+                .sum::<i64>()
+                .into(),
+        )
+    }
+
+    fn part_two(claw_machines: &Self::Input) -> Option<Answer> {
+        Some(
+            claw_machines
+                .iter()
+                .map(|claw_machine| ClawMachine {
+                    prize_position: (
+                        claw_machine.prize_position.0 + 10000000000000,
+                        claw_machine.prize_position.1 + 10000000000000,
+                    ),
+                    ..*claw_machine
+                })
+                .flat_map(|claw_machine| find_button_press_combination(&claw_machine))
+                .map(|(a, b)| calculate_token_cost(a, b))
+                .sum::<i64>()
+                .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let claw_machines = parse_input(
+            "Button A: X+94, Y+34\r\nButton B: X+22, Y+67\r\nPrize: X=8400, Y=5400\r\n\r\n\
+             Button A: X+26, Y+66\r\nButton B: X+67, Y+21\r\nPrize: X=12748, Y=12176\r\n",
+        )
+        .unwrap();
+        assert_eq!(claw_machines.len(), 2);
+        assert_eq!(claw_machines[1].prize_position, (12748, 12176));
+
+        assert_eq!(
+            parse_input("Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y-5400")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 16: expected `Y=`, found `Y-5400`"
+        );
+    }
+
+    #[test]
+    fn test_find_button_press_combination() {
+        let claw_machine = ClawMachine {
+            button_a_movement: (94, 34),
+            button_b_movement: (22, 67),
+            prize_position: (8400, 5400),
+        };
+        assert_eq!(find_button_press_combination(&claw_machine), Some((80, 40)));
+
+        let claw_machine = ClawMachine {
+            button_a_movement: (26, 66),
+            button_b_movement: (67, 21),
+            prize_position: (12748, 12176),
+        };
+        assert_eq!(find_button_press_combination(&claw_machine), None);
+
+        let claw_machine = ClawMachine {
+            button_a_movement: (17, 86),
+            button_b_movement: (84, 37),
+            prize_position: (7870, 6450),
+        };
+        assert_eq!(find_button_press_combination(&claw_machine), Some((38, 86)));
+
+        let claw_machine = ClawMachine {
+            button_a_movement: (69, 23),
+            button_b_movement: (27, 71),
+            prize_position: (18641, 10279),
+        };
+        assert_eq!(find_button_press_combination(&claw_machine), None);
+
+        // The presses would have to be negative.
+        let claw_machine = ClawMachine {
+            button_a_movement: (1, 0),
+            button_b_movement: (0, 1),
+            prize_position: (-2, 3),
+        };
+        assert_eq!(find_button_press_combination(&claw_machine), None);
+    }
+
+    #[test]
+    fn test_find_button_press_combination_with_parallel_buttons() {
+        let claw_machine = |button_a_movement, button_b_movement, prize_position| ClawMachine {
+            button_a_movement,
+            button_b_movement,
+            prize_position,
+        };
+        // A moves twice as far as B, but costs three times as much.
+        assert_eq!(
+            find_button_press_combination(&claw_machine((2, 2), (1, 1), (7, 7))),
+            Some((0, 7))
+        );
+        // A moves four times as far as B, which makes it the cheaper button.
+        assert_eq!(
+            find_button_press_combination(&claw_machine((4, 8), (1, 2), (10, 20))),
+            Some((2, 2))
+        );
+        assert_eq!(
+            find_button_press_combination(&claw_machine((2, 2), (4, 4), (5, 5))),
+            None
+        );
+        assert_eq!(
+            find_button_press_combination(&claw_machine((1, 1), (2, 2), (3, 4))),
+            None
+        );
+        assert_eq!(
+            find_button_press_combination(&claw_machine((0, 3), (0, 5), (0, 11))),
+            Some((2, 1))
+        );
+        assert_eq!(
+            find_button_press_combination(&claw_machine((0, 0), (0, 0), (0, 0))),
+            Some((0, 0))
+        );
+    }
+
+    #[test]
+    fn test_solve_presses() {
+        let claw_machine = |button_a_movement, button_b_movement, prize_position| ClawMachine {
+            button_a_movement,
+            button_b_movement,
+            prize_position,
+        };
+        assert_eq!(
+            solve_presses(&claw_machine((94, 34), (22, 67), (8400, 5400))),
+            Solutions::Unique(vec![80.into(), 40.into()])
+        );
+        // A unique solution that is not whole still counts as unique.
+        assert_eq!(
+            solve_presses(&claw_machine((2, 0), (0, 3), (1, 1))),
+            Solutions::Unique(vec![Fraction::new(1, 2), Fraction::new(1, 3)])
+        );
+        assert_eq!(
+            solve_presses(&claw_machine((1, 1), (2, 2), (3, 4))),
+            Solutions::None
+        );
+        assert!(matches!(
+            solve_presses(&claw_machine((2, 2), (1, 1), (7, 7))),
+            Solutions::Infinite { .. }
+        ));
+    }
+
+    #[test]
+    fn test_find_cheapest_solution() {
+        assert_eq!(find_cheapest_solution(LinearSolutions::None), None);
+        // 2a + 3b = 12: (0, 4), (3, 2) and (6, 0), of which (0, 4) costs the fewest tokens.
+        assert_eq!(
+            find_cheapest_solution(solve_linear_diophantine(2, 3, 12)),
+            Some((0, 4))
+        );
+        assert_eq!(
+            find_cheapest_solution(solve_linear_diophantine(2, 4, 1)),
+            None
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use crate::number_theory::chinese_remainder;
+use crate::parse::{ParseError, Span};
+use crate::point::Point;
+use crate::solution::{Answer, Example, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Area {
+    width: i32,
+    height: i32,
+}
+
+impl Area {
+    const PUZZLE: Area = Area {
+        width: 101,
+        height: 103,
+    };
+    const EXAMPLE: Area = Area {
+        width: 11,
+        height: 7,
+    };
+
+    fn contains(&self, point: Point) -> bool {
+        (0..self.width).contains(&point.x) && (0..self.height).contains(&point.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Robot {
+    position: Point,
+    velocity: Point,
+}
+
+impl Robot {
+    fn position_after(&self, seconds: i32, area: Area) -> Point {
+        (self.position + self.velocity * seconds).wrap(area.width, area.height)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Robots {
+    robots: Vec<Robot>,
+    area: Area,
+}
+
+fn parse_point(input: Span, prefix: &str) -> Result<Point, ParseError> {
+    let (x, y) = input
+        .strip_prefix(prefix)?
+        .split_once(",", "two numbers separated by `,`")?;
+    Ok(Point::new(x.parse("a number")?, y.parse("a number")?))
+}
+
+fn parse_robot(line: Span, area: Area) -> Result<Robot, ParseError> {
+    let (position, velocity) =
+        line.split_once(" ", "a position and a velocity separated by a space")?;
+    let robot = Robot {
+        position: parse_point(position, "p=")?,
+        velocity: parse_point(velocity, "v=")?,
+    };
+
+    if area.contains(robot.position) {
+        Ok(robot)
+    } else {
+        Err(position.error(&format!(
+            "a position inside the {}x{} area",
+            area.width, area.height
+        )))
+    }
+}
+
+fn parse_input(input: &str, area: Area) -> Result<Robots, ParseError> {
+    let robots = Span::new(input)
+        .lines()
+        .map(|line| parse_robot(line, area))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Robots { robots, area })
+}
+
+fn calculate_safety_factor(robots: &Robots, seconds: i32) -> usize {
+    let Robots { robots, area } = robots;
+    let (middle_x, middle_y) = (area.width / 2, area.height / 2);
+
+    let mut quadrants = [0; 4];
+    for position in robots
+        .iter()
+        .map(|robot| robot.position_after(seconds, *area))
+    {
+        if position.x != middle_x && position.y != middle_y {
+            let quadrant =
+                usize::from(position.x > middle_x) + 2 * usize::from(position.y > middle_y);
+            quadrants[quadrant] += 1;
+        }
+    }
+
+    quadrants.iter().product()
+}
+
+/// How spread out the values are, as variance times the squared number of values.
+fn spread(values: impl Iterator<Item = i32>) -> i64 {
+    let (count, sum, sum_of_squares) = values.fold((0, 0, 0), |(count, sum, squares), value| {
+        let value = value as i64;
+        (count + 1, sum + value, squares + value * value)
+    });
+    count * sum_of_squares - sum * sum
+}
+
+/// Finds the first second at which the robots are packed together most tightly, which is when
+/// they form a picture. The x coordinates repeat every `width` seconds and the y coordinates every
+/// `height` seconds, so both are minimised separately and then combined.
+fn find_picture_second(robots: &Robots) -> Option<i32> {
+    let Robots { robots, area } = robots;
+    let tightest_second = |period: i32, coordinate: fn(Point) -> i32| {
+        (0..period).min_by_key(|&seconds| {
+            spread(
+                robots
+                    .iter()
+                    .map(|robot| coordinate(robot.position_after(seconds, *area))),
+            )
+        })
+    };
+    let x_second = tightest_second(area.width, |point| point.x)?;
+    let y_second = tightest_second(area.height, |point| point.y)?;
+
+    let (second, _) = chinese_remainder(&[
+        (x_second.into(), area.width.into()),
+        (y_second.into(), area.height.into()),
+    ])?;
+    i32::try_from(second).ok()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Robots;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: include_str!("../inputs/examples/day_14.txt"),
+        part_one: Some("12"),
+        part_two: None,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input, Area::PUZZLE)
+    }
+
+    fn parse_example(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input, Area::EXAMPLE)
+    }
+
+    fn part_one(robots: &Self::Input) -> Option<Answer> {
+        Some(calculate_safety_factor(robots, 100).into())
+    }
+
+    fn part_two(robots: &Self::Input) -> Option<Answer> {
+        find_picture_second(robots).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Robots {
+        Day14::parse_example(Day14::EXAMPLES[0].input).unwrap()
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let robots = example();
+        assert_eq!(robots.robots.len(), 12);
+        assert_eq!(
+            robots.robots[0],
+            Robot {
+                position: Point::new(0, 4),
+                velocity: Point::new(3, -3)
+            }
+        );
+        assert_eq!(
+            parse_input("p=0,4 w=3,-3", Area::EXAMPLE)
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 7: expected `v=`, found `w=3,-3`"
+        );
+        assert_eq!(
+            parse_input("p=11,4 v=3,-3", Area::EXAMPLE)
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 1: expected a position inside the 11x7 area, found `p=11,4`"
+        );
+    }
+
+    #[test]
+    fn test_position_after() {
+        let robot = Robot {
+            position: Point::new(2, 4),
+            velocity: Point::new(2, -3),
+        };
+        let positions = (1..=5)
+            .map(|seconds| robot.position_after(seconds, Area::EXAMPLE))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            positions,
+            vec![
+                Point::new(4, 1),
+                Point::new(6, 5),
+                Point::new(8, 2),
+                Point::new(10, 6),
+                Point::new(1, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calculate_safety_factor() {
+        assert_eq!(calculate_safety_factor(&example(), 100), 12);
+    }
+
+    #[test]
+    fn test_find_picture_second() {
+        // Robots that meet in a small square after 40 seconds and drift apart otherwise.
+        let area = Area::EXAMPLE;
+        let velocities = [(1, 2), (-2, 3), (3, -1), (-1, -3), (2, 1), (-3, 2)];
+        let robots = velocities
+            .iter()
+            .enumerate()
+            .map(|(index, &(dx, dy))| {
+                let velocity = Point::new(dx, dy);
+                let target = Point::new(5 + index as i32 % 2, 3 + index as i32 / 4);
+                Robot {
+                    position: (target - velocity * 40).wrap(area.width, area.height),
+                    velocity,
+                }
+            })
+            .collect();
+        assert_eq!(find_picture_second(&Robots { robots, area }), Some(40));
+    }
+}
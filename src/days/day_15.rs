@@ -0,0 +1,301 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::grid::Grid;
+use crate::parse::{ParseError, Span};
+use crate::point::{Direction, Point};
+use crate::solution::{Answer, Example, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Empty,
+    Box,
+    BoxLeft,
+    BoxRight,
+}
+
+impl Tile {
+    fn symbol(self) -> char {
+        match self {
+            Self::Wall => '#',
+            Self::Empty => '.',
+            Self::Box => 'O',
+            Self::BoxLeft => '[',
+            Self::BoxRight => ']',
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Warehouse {
+    tiles: Grid<Tile>,
+    robot: Point,
+}
+
+impl Warehouse {
+    /// The same warehouse with every tile twice as wide and boxes spanning two tiles.
+    fn widen(&self) -> Self {
+        let rows = self
+            .tiles
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .flat_map(|tile| match tile {
+                        Tile::Box => [Tile::BoxLeft, Tile::BoxRight],
+                        tile => [*tile, *tile],
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            tiles: Grid::from_rows(rows).expect("rows of a grid have the same width"),
+            robot: Point::new(self.robot.x * 2, self.robot.y),
+        }
+    }
+
+    /// Moves the robot one step, pushing all boxes in the way. Wide boxes push every box that
+    /// touches either of their halves, so a vertical push can move a whole tree of boxes. Nothing
+    /// moves if any of them would run into a wall.
+    fn try_move(&mut self, direction: Direction) -> bool {
+        let offset = direction.offset();
+        let mut boxes = Vec::new();
+        let mut seen = HashSet::new();
+        let mut frontier = vec![self.robot + offset];
+
+        while let Some(position) = frontier.pop() {
+            let halves = match self.tiles.get_point(position) {
+                None | Some(Tile::Wall) => return false,
+                Some(Tile::Empty) => continue,
+                Some(Tile::Box) => [position, position],
+                Some(Tile::BoxLeft) => [position, position + Direction::Right.offset()],
+                Some(Tile::BoxRight) => [position + Direction::Left.offset(), position],
+            };
+            for half in halves {
+                if seen.insert(half) {
+                    boxes.push((half, self.tiles[half]));
+                    frontier.push(half + offset);
+                }
+            }
+        }
+
+        for &(position, _) in &boxes {
+            self.tiles[position] = Tile::Empty;
+        }
+        for (position, tile) in boxes {
+            self.tiles[position + offset] = tile;
+        }
+        self.robot += offset;
+        true
+    }
+
+    fn gps_coordinate_sum(&self) -> usize {
+        self.tiles
+            .iter()
+            .filter(|(_, tile)| matches!(tile, Tile::Box | Tile::BoxLeft))
+            .map(|((x, y), _)| 100 * y + x)
+            .sum()
+    }
+}
+
+impl fmt::Display for Warehouse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.tiles.rows().enumerate() {
+            for (x, tile) in row.iter().enumerate() {
+                if self.robot == Point::from((x, y)) {
+                    write!(f, "@")?;
+                } else {
+                    write!(f, "{}", tile.symbol())?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Instructions {
+    warehouse: Warehouse,
+    moves: Vec<Direction>,
+}
+
+fn parse_input(input: &str) -> Result<Instructions, ParseError> {
+    let input = Span::new(input);
+    let [map, moves] = input.blocks()[..] else {
+        return Err(input
+            .end()
+            .error("a warehouse map and a list of moves, separated by a blank line"));
+    };
+
+    let fields = Grid::parse(map.text(), "`#`, `.`, `O` or `@`", |field| {
+        matches!(field, '#' | '.' | 'O' | '@').then_some(field)
+    })?;
+    let robot = fields
+        .position(|field| *field == '@')
+        .ok_or_else(|| map.end().error("a robot `@`"))?;
+    let tiles = fields.map(|field| match field {
+        '#' => Tile::Wall,
+        'O' => Tile::Box,
+        _ => Tile::Empty,
+    });
+
+    let moves = moves
+        .lines()
+        .flat_map(|line| line.chars())
+        .map(|(arrow, span)| {
+            Direction::from_arrow(arrow).ok_or_else(|| span.error("a move `^`, `>`, `v` or `<`"))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Instructions {
+        warehouse: Warehouse { tiles, robot },
+        moves,
+    })
+}
+
+fn simulate(mut warehouse: Warehouse, moves: &[Direction]) -> Warehouse {
+    for &direction in moves {
+        warehouse.try_move(direction);
+    }
+    warehouse
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Instructions;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../inputs/examples/day_15.txt"),
+            part_one: Some("10092"),
+            part_two: Some("9021"),
+        },
+        Example {
+            name: "small",
+            input: include_str!("../inputs/examples/day_15_small.txt"),
+            part_one: Some("2028"),
+            part_two: None,
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(instructions: &Self::Input) -> Option<Answer> {
+        Some(
+            simulate(instructions.warehouse.clone(), &instructions.moves)
+                .gps_coordinate_sum()
+                .into(),
+        )
+    }
+
+    fn part_two(instructions: &Self::Input) -> Option<Answer> {
+        Some(
+            simulate(instructions.warehouse.widen(), &instructions.moves)
+                .gps_coordinate_sum()
+                .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDE_EXAMPLE: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^
+";
+
+    #[test]
+    fn test_parse_input() {
+        let instructions = parse_input(Day15::EXAMPLES[1].input).unwrap();
+        assert_eq!(instructions.warehouse.robot, Point::new(2, 2));
+        assert_eq!(instructions.moves.len(), 15);
+        assert_eq!(instructions.moves[..2], [Direction::Left, Direction::Up]);
+        assert_eq!(
+            parse_input("#@.#\n\n<x>").unwrap_err().to_string(),
+            "line 3, column 2: expected a move `^`, `>`, `v` or `<`, found `x`"
+        );
+        assert_eq!(
+            parse_input("#..#\n\n<").unwrap_err().to_string(),
+            "line 1, column 5: expected a robot `@`, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_simulate() {
+        let instructions = parse_input(Day15::EXAMPLES[1].input).unwrap();
+        let warehouse = simulate(instructions.warehouse, &instructions.moves);
+        assert_eq!(
+            warehouse.to_string(),
+            "\
+########
+#....OO#
+##.....#
+#.....O#
+#.#O@..#
+#...O..#
+#...O..#
+########
+"
+        );
+        assert_eq!(warehouse.gps_coordinate_sum(), 2028);
+    }
+
+    #[test]
+    fn test_simulate_wide() {
+        let instructions = parse_input(WIDE_EXAMPLE).unwrap();
+        let warehouse = instructions.warehouse.widen();
+        assert_eq!(
+            warehouse.to_string(),
+            "\
+##############
+##......##..##
+##..........##
+##....[][]@.##
+##....[]....##
+##..........##
+##############
+"
+        );
+
+        let warehouse = simulate(warehouse, &instructions.moves);
+        assert_eq!(
+            warehouse.to_string(),
+            "\
+##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############
+"
+        );
+        assert_eq!(warehouse.gps_coordinate_sum(), 618);
+    }
+
+    #[test]
+    fn test_blocked_tree_does_not_move() {
+        let instructions = parse_input("#####\n#...#\n#.#.#\n#.OO#\n#.@.#\n#####\n\n^").unwrap();
+        let mut warehouse = instructions.warehouse.widen();
+        let before = warehouse.clone();
+        assert!(!warehouse.try_move(Direction::Up));
+        assert_eq!(warehouse, before);
+    }
+}
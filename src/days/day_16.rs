@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+
+use crate::grid::Grid;
+use crate::parse::{ParseError, Span};
+use crate::point::{Direction, Point};
+use crate::search;
+use crate::solution::{Answer, Example, Solution};
+
+const MOVE_COST: u64 = 1;
+const TURN_COST: u64 = 1000;
+
+type State = (Point, Direction);
+
+#[derive(Debug)]
+pub struct Maze {
+    walls: Grid<bool>,
+    start: Point,
+    end: Point,
+}
+
+impl Maze {
+    fn is_open(&self, position: Point) -> bool {
+        self.walls.get_point(position) == Some(&false)
+    }
+
+    fn next_states(&self, (position, direction): State) -> impl Iterator<Item = (State, u64)> {
+        let forward = position + direction.offset();
+        let step = self
+            .is_open(forward)
+            .then_some(((forward, direction), MOVE_COST));
+        let turns = [direction.turn_left(), direction.turn_right()]
+            .map(|turned| ((position, turned), TURN_COST));
+        step.into_iter().chain(turns)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Maze, ParseError> {
+    let fields = Grid::parse(input, "`#`, `.`, `S` or `E`", |field| {
+        matches!(field, '#' | '.' | 'S' | 'E').then_some(field)
+    })?;
+    let find = |tile: char, expected: &str| {
+        fields
+            .position(|field| *field == tile)
+            .ok_or_else(|| Span::new(input).end().error(expected))
+    };
+
+    Ok(Maze {
+        start: find('S', "a start tile `S`")?,
+        end: find('E', "an end tile `E`")?,
+        walls: fields.map(|field| *field == '#'),
+    })
+}
+
+struct BestPaths {
+    score: u64,
+    /// Every state on a lowest-score path from the start to the end.
+    states: HashSet<State>,
+}
+
+/// Dijkstra over positions with their facing. The search keeps every predecessor that reaches a
+/// state with its lowest score, so the best paths to all facings at the end are covered, not just
+/// one.
+fn find_best_paths(maze: &Maze) -> Option<BestPaths> {
+    let search = search::dijkstra(
+        [(maze.start, Direction::Right)],
+        |&state| maze.next_states(state),
+        |&(position, _)| position == maze.end,
+    );
+
+    Some(BestPaths {
+        score: search.goal_cost()?,
+        states: search.states_on_paths(search.goals()),
+    })
+}
+
+fn count_best_path_tiles(best_paths: &BestPaths) -> usize {
+    best_paths
+        .states
+        .iter()
+        .map(|(position, _)| position)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Maze;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../inputs/examples/day_16.txt"),
+            part_one: Some("7036"),
+            part_two: Some("45"),
+        },
+        Example {
+            name: "second",
+            input: include_str!("../inputs/examples/day_16_second.txt"),
+            part_one: Some("11048"),
+            part_two: Some("64"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(maze: &Self::Input) -> Option<Answer> {
+        find_best_paths(maze).map(|best_paths| best_paths.score.into())
+    }
+
+    fn part_two(maze: &Self::Input) -> Option<Answer> {
+        find_best_paths(maze).map(|best_paths| count_best_path_tiles(&best_paths).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let maze = parse_input(Day16::EXAMPLES[0].input).unwrap();
+        assert_eq!(maze.start, Point::new(1, 13));
+        assert_eq!(maze.end, Point::new(13, 1));
+        assert!(maze.walls[(0, 0)]);
+        assert!(!maze.walls[(1, 1)]);
+        assert_eq!(
+            parse_input("#####\n#S.##\n#####").unwrap_err().to_string(),
+            "line 3, column 6: expected an end tile `E`, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_find_best_paths() {
+        let maze = parse_input("#####\n#S.E#\n#####").unwrap();
+        let best_paths = find_best_paths(&maze).unwrap();
+        assert_eq!(best_paths.score, 2);
+        assert_eq!(count_best_path_tiles(&best_paths), 3);
+
+        let maze = parse_input("#####\n#...#\n#S#E#\n#...#\n#####").unwrap();
+        let best_paths = find_best_paths(&maze).unwrap();
+        assert_eq!(best_paths.score, 3004);
+        assert_eq!(count_best_path_tiles(&best_paths), 8);
+
+        let maze = parse_input("#####\n#S#E#\n#####").unwrap();
+        assert!(find_best_paths(&maze).is_none());
+    }
+}
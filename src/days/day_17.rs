@@ -0,0 +1,288 @@
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Example, Solution};
+
+const ADV: u8 = 0;
+const BXL: u8 = 1;
+const BST: u8 = 2;
+const JNZ: u8 = 3;
+const BXC: u8 = 4;
+const OUT: u8 = 5;
+const BDV: u8 = 6;
+const CDV: u8 = 7;
+
+/// Programs that halt run their loop once per output, so they finish far below this. One that
+/// exceeds it is assumed to never halt.
+const MAX_STEPS: usize = 1 << 20;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Computer {
+    registers: [u64; 3],
+    program: Vec<u8>,
+}
+
+fn takes_combo_operand(opcode: u8) -> bool {
+    !matches!(opcode, BXL | JNZ | BXC)
+}
+
+fn parse_register(line: Option<Span>, name: char, block: Span) -> Result<u64, ParseError> {
+    let expected = format!("`Register {name}: ` and a number");
+    let line = line.ok_or_else(|| block.end().error(&expected))?;
+    line.strip_prefix(&format!("Register {name}: "))?
+        .parse("a number")
+}
+
+fn parse_program(line: Span) -> Result<Vec<u8>, ParseError> {
+    let program = line
+        .strip_prefix("Program: ")?
+        .split(',')
+        .map(|value| match value.parse::<u8>("a 3-bit number")? {
+            number @ 0..8 => Ok((number, value)),
+            _ => Err(value.error("a 3-bit number")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for instruction in program.chunks(2) {
+        if let [(opcode, _), (7, operand)] = instruction {
+            if takes_combo_operand(*opcode) {
+                return Err(operand.error("a combo operand other than the reserved 7"));
+            }
+        }
+    }
+
+    Ok(program.into_iter().map(|(number, _)| number).collect())
+}
+
+fn parse_input(input: &str) -> Result<Computer, ParseError> {
+    let input = Span::new(input);
+    let [registers, program] = input.blocks()[..] else {
+        return Err(input
+            .end()
+            .error("registers and a program, separated by a blank line"));
+    };
+
+    let mut lines = registers.lines();
+    let registers = [
+        parse_register(lines.next(), 'A', registers)?,
+        parse_register(lines.next(), 'B', registers)?,
+        parse_register(lines.next(), 'C', registers)?,
+    ];
+    if let Some(line) = lines.next() {
+        return Err(line.error("no more registers"));
+    }
+
+    Ok(Computer {
+        registers,
+        program: parse_program(program)?,
+    })
+}
+
+/// Runs the program until the instruction pointer leaves it and returns everything it output, or
+/// `None` if it is still running after [`MAX_STEPS`] instructions. Parsing only rejects the
+/// reserved combo operand at even positions, but `jnz` can jump to odd ones, so running into it
+/// gives `None` as well.
+fn run(program: &[u8], registers: &mut [u64; 3]) -> Option<Vec<u8>> {
+    let [a, b, c] = registers;
+    let mut output = Vec::new();
+    let mut pointer = 0;
+
+    for _ in 0..MAX_STEPS {
+        let [opcode, operand, ..] = program[pointer.min(program.len())..] else {
+            return Some(output);
+        };
+        let literal = u64::from(operand);
+        let combo = match operand {
+            _ if !takes_combo_operand(opcode) => literal,
+            0..4 => literal,
+            4 => *a,
+            5 => *b,
+            6 => *c,
+            _ => return None,
+        };
+        let divide = |value: u64| {
+            u32::try_from(combo)
+                .ok()
+                .and_then(|shift| value.checked_shr(shift))
+                .unwrap_or(0)
+        };
+
+        pointer += 2;
+        match opcode {
+            ADV => *a = divide(*a),
+            BXL => *b ^= literal,
+            BST => *b = combo % 8,
+            JNZ if *a != 0 => pointer = operand as usize,
+            JNZ => {}
+            BXC => *b ^= *c,
+            OUT => output.push((combo % 8) as u8),
+            BDV => *b = divide(*a),
+            CDV => *c = divide(*a),
+            _ => unreachable!("opcodes are 3-bit numbers"),
+        }
+    }
+
+    None
+}
+
+fn format_output(output: &[u8]) -> String {
+    output
+        .iter()
+        .map(u8::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Finds the lowest register A for which the program outputs itself. Such programs shift A three
+/// bits to the right per output, so the last output only depends on the highest three bits of A.
+/// The search fixes A three bits at a time, from the last output backwards, keeping only the
+/// candidates that already produce the matching tail of the program.
+fn find_quine_register(computer: &Computer) -> Option<u64> {
+    fn search(computer: &Computer, index: usize, prefix: u64) -> Option<u64> {
+        let [_, b, c] = computer.registers;
+        (0..8).find_map(|bits| {
+            let a = prefix.checked_mul(8)? | bits;
+            if run(&computer.program, &mut [a, b, c]).as_deref() != Some(&computer.program[index..])
+            {
+                return None;
+            }
+            match index {
+                0 => Some(a),
+                _ => search(computer, index - 1, a),
+            }
+        })
+    }
+
+    let last = computer.program.len().checked_sub(1)?;
+    search(computer, last, 0)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Computer;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../inputs/examples/day_17.txt"),
+            part_one: Some("4,6,3,5,6,3,5,2,1,0"),
+            part_two: None,
+        },
+        Example {
+            name: "quine",
+            input: include_str!("../inputs/examples/day_17_quine.txt"),
+            part_one: Some("5,7,3,0"),
+            part_two: Some("117440"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(computer: &Self::Input) -> Option<Answer> {
+        run(&computer.program, &mut computer.registers.clone())
+            .map(|output| format_output(&output).into())
+    }
+
+    fn part_two(computer: &Self::Input) -> Option<Answer> {
+        find_quine_register(computer).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            parse_input(Day17::EXAMPLES[0].input),
+            Ok(Computer {
+                registers: [729, 0, 0],
+                program: vec![0, 1, 5, 4, 3, 0]
+            })
+        );
+        assert_eq!(
+            parse_input("Register A: 1\nRegister B: 0\n\nProgram: 0,1")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 14: expected `Register C: ` and a number, found end of line"
+        );
+        assert_eq!(
+            parse_input("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,8")
+                .unwrap_err()
+                .to_string(),
+            "line 5, column 16: expected a 3-bit number, found `8`"
+        );
+        assert_eq!(
+            parse_input("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,7")
+                .unwrap_err()
+                .to_string(),
+            "line 5, column 16: expected a combo operand other than the reserved 7, found `7`"
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let mut registers = [0, 0, 9];
+        assert_eq!(run(&[BST, 6], &mut registers), Some(vec![]));
+        assert_eq!(registers, [0, 1, 9]);
+
+        assert_eq!(
+            run(&[OUT, 0, OUT, 1, OUT, 4], &mut [10, 0, 0]),
+            Some(vec![0, 1, 2])
+        );
+
+        let mut registers = [2024, 0, 0];
+        assert_eq!(
+            format_output(&run(&[ADV, 1, OUT, 4, JNZ, 0], &mut registers).unwrap()),
+            "4,2,5,6,7,7,7,7,3,1,0"
+        );
+        assert_eq!(registers, [0, 0, 0]);
+
+        let mut registers = [0, 29, 0];
+        run(&[BXL, 7], &mut registers);
+        assert_eq!(registers, [0, 26, 0]);
+
+        let mut registers = [0, 2024, 43690];
+        run(&[BXC, 0], &mut registers);
+        assert_eq!(registers, [0, 44354, 43690]);
+
+        let mut registers = [64, 0, 0];
+        run(&[BDV, 2, CDV, 3], &mut registers);
+        assert_eq!(registers, [64, 16, 8]);
+
+        // Shifts by a register value that does not fit into 32 bits clear the result.
+        let mut registers = [1 << 40, 1 << 32, 0];
+        run(&[ADV, 5], &mut registers);
+        assert_eq!(registers, [0, 1 << 32, 0]);
+
+        // Jumping to an odd address reads `7, 0` as an instruction with the reserved operand.
+        let computer =
+            parse_input("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,1,1,5,7,0")
+                .unwrap();
+        assert_eq!(
+            run(&computer.program, &mut computer.registers.clone()),
+            None
+        );
+        assert_eq!(Day17::part_one(&computer), None);
+    }
+
+    #[test]
+    fn test_run_without_halting() {
+        assert_eq!(run(&[OUT, 4, JNZ, 0], &mut [1, 0, 0]), None);
+        assert_eq!(run(&[JNZ, 0], &mut [1, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_find_quine_register() {
+        let computer = parse_input(Day17::EXAMPLES[1].input).unwrap();
+        assert_eq!(find_quine_register(&computer), Some(117440));
+        assert_eq!(
+            run(&computer.program, &mut [117440, 0, 0]),
+            Some(computer.program)
+        );
+    }
+}
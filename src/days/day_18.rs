@@ -0,0 +1,185 @@
+use crate::grid::Grid;
+use crate::parse::{ParseError, Span};
+use crate::point::Point;
+use crate::search;
+use crate::solution::{Answer, Example, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemorySpace {
+    size: usize,
+    /// How many bytes have fallen when the shortest path is asked for.
+    fallen_bytes: usize,
+}
+
+impl MemorySpace {
+    const PUZZLE: MemorySpace = MemorySpace {
+        size: 71,
+        fallen_bytes: 1024,
+    };
+    const EXAMPLE: MemorySpace = MemorySpace {
+        size: 7,
+        fallen_bytes: 12,
+    };
+
+    fn contains(&self, point: Point) -> bool {
+        let size = self.size as i32;
+        (0..size).contains(&point.x) && (0..size).contains(&point.y)
+    }
+
+    fn exit(&self) -> Point {
+        let corner = self.size as i32 - 1;
+        Point::new(corner, corner)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FallingBytes {
+    bytes: Vec<Point>,
+    space: MemorySpace,
+}
+
+fn parse_byte(line: Span, space: MemorySpace) -> Result<Point, ParseError> {
+    let (x, y) = line.split_once(",", "two coordinates separated by `,`")?;
+    let byte = Point::new(x.parse("a coordinate")?, y.parse("a coordinate")?);
+
+    if space.contains(byte) {
+        Ok(byte)
+    } else {
+        Err(line.error(&format!(
+            "a position inside the {0}x{0} memory space",
+            space.size
+        )))
+    }
+}
+
+fn parse_input(input: &str, space: MemorySpace) -> Result<FallingBytes, ParseError> {
+    let bytes = Span::new(input)
+        .lines()
+        .map(|line| parse_byte(line, space))
+        .collect::<Result<_, _>>()?;
+
+    Ok(FallingBytes { bytes, space })
+}
+
+/// The number of steps from the top left to the bottom right corner once `bytes` have fallen.
+fn find_shortest_path(space: MemorySpace, bytes: &[Point]) -> Option<usize> {
+    let mut corrupted = Grid::filled(space.size, space.size, false);
+    for &byte in bytes {
+        corrupted[byte] = true;
+    }
+    if corrupted[Point::ORIGIN] {
+        return None;
+    }
+
+    let exit = space.exit();
+    // The Manhattan distance never overestimates the steps left, so A* finds the shortest path.
+    search::a_star(
+        [Point::ORIGIN],
+        |&position: &Point| {
+            position
+                .neighbours_4()
+                .filter(|&neighbour| corrupted.get_point(neighbour) == Some(&false))
+                .map(|neighbour| (neighbour, 1))
+        },
+        |&position| position.manhattan_distance(exit) as usize,
+        |&position| position == exit,
+    )
+    .goal_cost()
+}
+
+/// Binary searches for the shortest prefix of the bytes that cuts the exit off. Once cut off, the
+/// exit stays unreachable, so this takes a logarithmic number of searches instead of one per byte.
+fn find_first_blocking_byte(falling_bytes: &FallingBytes) -> Option<Point> {
+    let FallingBytes { bytes, space } = falling_bytes;
+    let is_reachable = |fallen: usize| find_shortest_path(*space, &bytes[..fallen]).is_some();
+    if is_reachable(bytes.len()) {
+        return None;
+    }
+
+    // The exit is reachable after `reachable` bytes and cut off after `blocked` bytes.
+    let (mut reachable, mut blocked) = (0, bytes.len());
+    while blocked - reachable > 1 {
+        let middle = reachable + (blocked - reachable) / 2;
+        if is_reachable(middle) {
+            reachable = middle;
+        } else {
+            blocked = middle;
+        }
+    }
+
+    Some(bytes[blocked - 1])
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = FallingBytes;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: include_str!("../inputs/examples/day_18.txt"),
+        part_one: Some("22"),
+        part_two: Some("6,1"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input, MemorySpace::PUZZLE)
+    }
+
+    fn parse_example(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input, MemorySpace::EXAMPLE)
+    }
+
+    fn part_one(falling_bytes: &Self::Input) -> Option<Answer> {
+        let FallingBytes { bytes, space } = falling_bytes;
+        let fallen = space.fallen_bytes.min(bytes.len());
+        find_shortest_path(*space, &bytes[..fallen]).map(Answer::from)
+    }
+
+    fn part_two(falling_bytes: &Self::Input) -> Option<Answer> {
+        find_first_blocking_byte(falling_bytes).map(|byte| byte.to_string().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> FallingBytes {
+        Day18::parse_example(Day18::EXAMPLES[0].input).unwrap()
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let falling_bytes = example();
+        assert_eq!(falling_bytes.bytes.len(), 25);
+        assert_eq!(falling_bytes.bytes[0], Point::new(5, 4));
+        assert_eq!(
+            parse_input("1,2\n7,0", MemorySpace::EXAMPLE)
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: expected a position inside the 7x7 memory space, found `7,0`"
+        );
+    }
+
+    #[test]
+    fn test_find_shortest_path() {
+        let FallingBytes { bytes, space } = example();
+        assert_eq!(find_shortest_path(space, &[]), Some(12));
+        assert_eq!(find_shortest_path(space, &bytes[..12]), Some(22));
+        assert_eq!(find_shortest_path(space, &bytes), None);
+    }
+
+    #[test]
+    fn test_find_first_blocking_byte() {
+        let mut falling_bytes = example();
+        assert_eq!(
+            find_first_blocking_byte(&falling_bytes),
+            Some(Point::new(6, 1))
+        );
+        falling_bytes.bytes.truncate(20);
+        assert_eq!(find_first_blocking_byte(&falling_bytes), None);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Example, Solution};
+
+const COLORS: [u8; 5] = [b'w', b'u', b'b', b'r', b'g'];
+
+fn color_index(color: u8) -> Option<usize> {
+    COLORS.iter().position(|&known| known == color)
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct TrieNode {
+    children: [Option<usize>; COLORS.len()],
+    is_pattern: bool,
+}
+
+/// The towel patterns as a prefix tree, so all patterns that start a design are found in one walk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PatternTrie {
+    nodes: Vec<TrieNode>,
+}
+
+impl PatternTrie {
+    fn new() -> Self {
+        Self {
+            nodes: vec![TrieNode::default()],
+        }
+    }
+
+    fn insert(&mut self, pattern: &[usize]) {
+        let mut node = 0;
+        for &color in pattern {
+            node = match self.nodes[node].children[color] {
+                Some(child) => child,
+                None => {
+                    self.nodes.push(TrieNode::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children[color] = Some(child);
+                    child
+                }
+            };
+        }
+        self.nodes[node].is_pattern = true;
+    }
+
+    /// The lengths of all patterns that `design` starts with.
+    fn prefix_lengths<'a>(&'a self, design: &'a [usize]) -> impl Iterator<Item = usize> + 'a {
+        design
+            .iter()
+            .scan(0, |node, &color| {
+                *node = self.nodes[*node].children[color]?;
+                Some(*node)
+            })
+            .enumerate()
+            .filter(|(_, node)| self.nodes[*node].is_pattern)
+            .map(|(index, _)| index + 1)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Onsen {
+    patterns: PatternTrie,
+    designs: Vec<Vec<usize>>,
+}
+
+fn parse_colors(stripes: Span) -> Result<Vec<usize>, ParseError> {
+    if stripes.is_empty() {
+        return Err(stripes.error("at least one stripe"));
+    }
+    stripes
+        .chars()
+        .map(|(color, span)| {
+            u8::try_from(color)
+                .ok()
+                .and_then(color_index)
+                .ok_or_else(|| span.error("a stripe color `w`, `u`, `b`, `r` or `g`"))
+        })
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Onsen, ParseError> {
+    let input = Span::new(input);
+    let [patterns_input, designs_input] = input.blocks()[..] else {
+        return Err(input
+            .end()
+            .error("towel patterns and designs, separated by a blank line"));
+    };
+
+    let mut patterns = PatternTrie::new();
+    for pattern in patterns_input.split(',') {
+        patterns.insert(&parse_colors(pattern.trim())?);
+    }
+    let designs = designs_input
+        .lines()
+        .map(parse_colors)
+        .collect::<Result<_, _>>()?;
+
+    Ok(Onsen { patterns, designs })
+}
+
+/// Counts the ways to compose `design` from the patterns, remembering the count for every suffix
+/// so that each one is only counted once.
+fn count_arrangements(patterns: &PatternTrie, design: &[usize]) -> u64 {
+    fn count_suffix(
+        patterns: &PatternTrie,
+        design: &[usize],
+        start: usize,
+        counts: &mut [Option<u64>],
+    ) -> u64 {
+        if start == design.len() {
+            return 1;
+        }
+        if let Some(count) = counts[start] {
+            return count;
+        }
+
+        let count = patterns
+            .prefix_lengths(&design[start..])
+            .map(|length| count_suffix(patterns, design, start + length, counts))
+            .sum();
+        counts[start] = Some(count);
+        count
+    }
+
+    count_suffix(patterns, design, 0, &mut vec![None; design.len()])
+}
+
+fn arrangement_counts(onsen: &Onsen) -> impl Iterator<Item = u64> + '_ {
+    onsen
+        .designs
+        .iter()
+        .map(|design| count_arrangements(&onsen.patterns, design))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = Onsen;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: include_str!("../inputs/examples/day_19.txt"),
+        part_one: Some("6"),
+        part_two: Some("16"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(onsen: &Self::Input) -> Option<Answer> {
+        Some(
+            arrangement_counts(onsen)
+                .filter(|&count| count > 0)
+                .count()
+                .into(),
+        )
+    }
+
+    fn part_two(onsen: &Self::Input) -> Option<Answer> {
+        Some(arrangement_counts(onsen).sum::<u64>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn colors(stripes: &str) -> Vec<usize> {
+        parse_colors(Span::new(stripes)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let onsen = parse_input(Day19::EXAMPLES[0].input).unwrap();
+        assert_eq!(onsen.designs.len(), 8);
+        assert_eq!(onsen.designs[0], colors("brwrr"));
+        assert_eq!(
+            parse_input("r, wx\n\nrw").unwrap_err().to_string(),
+            "line 1, column 5: expected a stripe color `w`, `u`, `b`, `r` or `g`, found `x`"
+        );
+        assert_eq!(
+            parse_input("r, , b\n\nrb").unwrap_err().to_string(),
+            "line 1, column 3: expected at least one stripe, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_prefix_lengths() {
+        let onsen = parse_input(Day19::EXAMPLES[0].input).unwrap();
+        let design = colors("bwurrg");
+        assert_eq!(
+            onsen.patterns.prefix_lengths(&design).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+    }
+
+    #[test]
+    fn test_count_arrangements() {
+        let onsen = parse_input(Day19::EXAMPLES[0].input).unwrap();
+        let counts = arrangement_counts(&onsen).collect::<Vec<_>>();
+        assert_eq!(counts, vec![2, 1, 4, 6, 0, 1, 2, 0]);
+
+        let mut patterns = PatternTrie::new();
+        patterns.insert(&colors("w"));
+        patterns.insert(&colors("ww"));
+        // Compositions of 60 into parts of 1 and 2 are counted by the Fibonacci numbers.
+        assert_eq!(
+            count_arrangements(&patterns, &colors(&"w".repeat(60))),
+            2_504_730_781_961
+        );
+    }
+}
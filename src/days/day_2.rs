@@ -0,0 +1,126 @@
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Example, Solution};
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|level| level.parse("a level"))
+                .collect()
+        })
+        .collect()
+}
+
+fn get_level_diffs(report: &[i32]) -> Vec<i32> {
+    report
+        .windows(2)
+        .map(|level_pair| level_pair[1] - level_pair[0])
+        .collect()
+}
+
+fn check_save_level_differences(report: &[i32], save_diff_predicate: fn(&i32) -> bool) -> bool {
+    get_level_diffs(report).iter().all(save_diff_predicate)
+}
+
+fn is_save_report(report: &[i32]) -> bool {
+    let all_increasing = check_save_level_differences(report, |diff| *diff > 0 && *diff < 4);
+    let all_decreasing = check_save_level_differences(report, |diff| *diff < 0 && *diff > -4);
+
+    all_increasing || all_decreasing
+}
+
+fn generate_possible_reports_with_one_missing_level(report: &[i32]) -> Vec<Vec<i32>> {
+    (0..report.len())
+        .map(|tolerance_index| {
+            (0..report.len())
+                .filter(|index| *index != tolerance_index)
+                .map(|index| report[index])
+                .collect::<Vec<i32>>()
+        })
+        .collect()
+}
+
+fn is_save_report_with_tolerance(report: &[i32]) -> bool {
+    generate_possible_reports_with_one_missing_level(report)
+        .iter()
+        .any(|report_with_one_missing_level| is_save_report(report_with_one_missing_level))
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i32>>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: include_str!("../inputs/examples/day_2.txt"),
+        part_one: Some("2"),
+        part_two: Some("4"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(reports: &Self::Input) -> Option<Answer> {
+        Some(
+            reports
+                .iter()
+                .filter(|report| is_save_report(report))
+                .count()
+                .into(),
+        )
+    }
+
+    fn part_two(reports: &Self::Input) -> Option<Answer> {
+        Some(
+            reports
+                .iter()
+                .filter(|report| is_save_report_with_tolerance(report))
+                .count()
+                .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_save_report() {
+        assert!(is_save_report(&[7, 6, 4, 2, 1]));
+        assert!(!is_save_report(&[1, 2, 7, 8, 9]));
+        assert!(!is_save_report(&[9, 7, 6, 2, 1]));
+        assert!(!is_save_report(&[1, 3, 2, 4, 5]));
+        assert!(!is_save_report(&[8, 6, 4, 4, 1]));
+        assert!(is_save_report(&[1, 3, 6, 7, 9]));
+    }
+
+    #[test]
+    fn test_generate_possible_reports_with_one_missing_level() {
+        assert_eq!(
+            generate_possible_reports_with_one_missing_level(&[0, 1, 2, 3, 4]),
+            vec![
+                vec![1, 2, 3, 4],
+                vec![0, 2, 3, 4],
+                vec![0, 1, 3, 4],
+                vec![0, 1, 2, 4],
+                vec![0, 1, 2, 3]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_save_report_with_tolerance() {
+        assert!(is_save_report_with_tolerance(&[7, 6, 4, 2, 1]));
+        assert!(!is_save_report_with_tolerance(&[1, 2, 7, 8, 9]));
+        assert!(!is_save_report_with_tolerance(&[9, 7, 6, 2, 1]));
+        assert!(is_save_report_with_tolerance(&[1, 3, 2, 4, 5]));
+        assert!(is_save_report_with_tolerance(&[8, 6, 4, 4, 1]));
+        assert!(is_save_report_with_tolerance(&[1, 3, 6, 7, 9]));
+    }
+}
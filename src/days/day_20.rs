@@ -0,0 +1,180 @@
+use crate::grid::Grid;
+use crate::parse::{ParseError, Span};
+use crate::point::Point;
+use crate::search;
+use crate::solution::{Answer, Example, Solution};
+
+const PUZZLE_MINIMUM_SAVING: usize = 100;
+const EXAMPLE_MINIMUM_SAVING: usize = 50;
+
+#[derive(Debug)]
+pub struct Racetrack {
+    walls: Grid<bool>,
+    start: Point,
+    end: Point,
+    /// How many picoseconds a cheat has to save to be counted.
+    minimum_saving: usize,
+}
+
+fn parse_input(input: &str, minimum_saving: usize) -> Result<Racetrack, ParseError> {
+    let fields = Grid::parse(input, "`#`, `.`, `S` or `E`", |field| {
+        matches!(field, '#' | '.' | 'S' | 'E').then_some(field)
+    })?;
+    let find = |tile: char, expected: &str| {
+        fields
+            .position(|field| *field == tile)
+            .ok_or_else(|| Span::new(input).end().error(expected))
+    };
+
+    Ok(Racetrack {
+        start: find('S', "a start tile `S`")?,
+        end: find('E', "an end tile `E`")?,
+        walls: fields.map(|field| *field == '#'),
+        minimum_saving,
+    })
+}
+
+/// The number of steps from `origin` to every track position, without cheating.
+fn find_distances(walls: &Grid<bool>, origin: Point) -> Grid<Option<usize>> {
+    let search = search::bfs(
+        [origin],
+        |&position: &Point| {
+            position
+                .neighbours_4()
+                .filter(|&neighbour| walls.get_point(neighbour) == Some(&false))
+        },
+        |_| false,
+    );
+
+    let mut distances = walls.map(|_| None);
+    for (&position, &distance) in search.costs() {
+        distances[position] = Some(distance);
+    }
+    distances
+}
+
+/// All non-zero offsets of at most `radius` steps.
+fn offsets_within(radius: i32) -> impl Iterator<Item = Point> {
+    (-radius..=radius).flat_map(move |dy| {
+        let width = radius - dy.abs();
+        (-width..=width)
+            .map(move |dx| Point::new(dx, dy))
+            .filter(|&offset| offset != Point::ORIGIN)
+    })
+}
+
+/// Counts the cheats of at most `cheat_length` steps through walls that save at least the
+/// racetrack's `minimum_saving` picoseconds. A cheat from `a` to `b` takes the distance from the
+/// start to `a`, the Manhattan distance between them, and the distance from `b` to the end, so
+/// every cheat is checked in constant time once both distance maps are known.
+fn count_cheats(racetrack: &Racetrack, cheat_length: i32) -> usize {
+    let from_start = find_distances(&racetrack.walls, racetrack.start);
+    let to_end = find_distances(&racetrack.walls, racetrack.end);
+    let Some(fair_time) = from_start[racetrack.end] else {
+        return 0;
+    };
+    let offsets = offsets_within(cheat_length).collect::<Vec<_>>();
+
+    from_start
+        .iter()
+        .filter_map(|(position, distance)| Some((Point::from(position), (*distance)?)))
+        .map(|(cheat_start, start_distance)| {
+            offsets
+                .iter()
+                .filter(|&&offset| {
+                    let Some(&Some(end_distance)) = to_end.get_point(cheat_start + offset) else {
+                        return false;
+                    };
+                    let cheat_time = start_distance
+                        + offset.manhattan_distance(Point::ORIGIN) as usize
+                        + end_distance;
+                    cheat_time + racetrack.minimum_saving <= fair_time
+                })
+                .count()
+        })
+        .sum()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Racetrack;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: include_str!("../inputs/examples/day_20.txt"),
+        part_one: Some("1"),
+        part_two: Some("285"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input, PUZZLE_MINIMUM_SAVING)
+    }
+
+    fn parse_example(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input, EXAMPLE_MINIMUM_SAVING)
+    }
+
+    fn part_one(racetrack: &Self::Input) -> Option<Answer> {
+        Some(count_cheats(racetrack, 2).into())
+    }
+
+    fn part_two(racetrack: &Self::Input) -> Option<Answer> {
+        Some(count_cheats(racetrack, 20).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Racetrack {
+        Day20::parse_example(Day20::EXAMPLES[0].input).unwrap()
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let racetrack = example();
+        assert_eq!(racetrack.start, Point::new(1, 3));
+        assert_eq!(racetrack.end, Point::new(5, 7));
+        assert_eq!(
+            parse_input("#S.#\n#.x#", 1).unwrap_err().to_string(),
+            "line 2, column 3: expected `#`, `.`, `S` or `E`, found `x`"
+        );
+    }
+
+    #[test]
+    fn test_find_distances() {
+        let racetrack = example();
+        let distances = find_distances(&racetrack.walls, racetrack.start);
+        assert_eq!(distances[racetrack.end], Some(84));
+        assert_eq!(distances[Point::ORIGIN], None);
+    }
+
+    #[test]
+    fn test_offsets_within() {
+        assert_eq!(offsets_within(1).count(), 4);
+        assert_eq!(offsets_within(2).count(), 12);
+        assert_eq!(offsets_within(20).count(), 840);
+    }
+
+    #[test]
+    fn test_count_cheats() {
+        let count = |cheat_length, minimum_saving| {
+            let racetrack = Racetrack {
+                minimum_saving,
+                ..example()
+            };
+            count_cheats(&racetrack, cheat_length)
+        };
+        assert_eq!(count(2, 2), 44);
+        assert_eq!(count(2, 20), 5);
+        assert_eq!(count(2, 64), 1);
+        assert_eq!(count(2, 65), 0);
+        assert_eq!(count(20, 50), 285);
+        assert_eq!(count(20, 74), 7);
+        assert_eq!(count(20, 76), 3);
+    }
+}
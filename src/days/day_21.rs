@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+use std::iter;
+
+use crate::parse::{ParseError, Span};
+use crate::point::{Direction, Point};
+use crate::solution::{Answer, Example, Solution};
+
+struct Keypad {
+    /// The keys by row, with a space for the empty corner.
+    rows: &'static [&'static str],
+}
+
+impl Keypad {
+    const NUMERIC: Keypad = Keypad {
+        rows: &["789", "456", "123", " 0A"],
+    };
+    const DIRECTIONAL: Keypad = Keypad {
+        rows: &[" ^A", "<v>"],
+    };
+
+    fn position(&self, key: char) -> Point {
+        self.rows
+            .iter()
+            .enumerate()
+            .find_map(|(y, row)| row.find(key).map(|x| Point::from((x, y))))
+            .unwrap_or_else(|| panic!("key `{key}` is not on the keypad"))
+    }
+
+    /// The key sequences that move an arm from `from` to `to` and press it: all horizontal moves
+    /// first or all vertical moves first, as long as the arm does not pass the empty corner. Mixing
+    /// horizontal and vertical moves is never cheaper, because every extra change of direction
+    /// costs extra presses further up the chain.
+    fn move_sequences(&self, from: char, to: char) -> Vec<Vec<char>> {
+        let (start, end) = (self.position(from), self.position(to));
+        let gap = self.position(' ');
+        let moves = |direction: Direction, count: i32| {
+            iter::repeat_n(direction.arrow(), count.unsigned_abs() as usize)
+        };
+        let offset = end - start;
+        let horizontal = moves(
+            if offset.x < 0 {
+                Direction::Left
+            } else {
+                Direction::Right
+            },
+            offset.x,
+        );
+        let vertical = moves(
+            if offset.y < 0 {
+                Direction::Up
+            } else {
+                Direction::Down
+            },
+            offset.y,
+        );
+
+        let mut sequences = Vec::new();
+        if Point::new(end.x, start.y) != gap {
+            sequences.push(
+                horizontal
+                    .clone()
+                    .chain(vertical.clone())
+                    .chain(['A'])
+                    .collect(),
+            );
+        }
+        if Point::new(start.x, end.y) != gap {
+            sequences.push(vertical.chain(horizontal).chain(['A']).collect());
+        }
+        sequences.dedup();
+        sequences
+    }
+}
+
+/// A numeric keypad driven by a chain of robots at directional keypads, with the human at the
+/// directional keypad that controls the last robot.
+struct KeypadChain {
+    robots: usize,
+    /// The presses it takes to move an arm at a directional keypad from one key to another and
+    /// press it, by the number of robots between that arm and the human.
+    costs: HashMap<(char, char, usize), u64>,
+}
+
+impl KeypadChain {
+    fn new(robots: usize) -> Self {
+        Self {
+            robots,
+            costs: HashMap::new(),
+        }
+    }
+
+    /// The presses it takes to type `sequence` on a directional keypad, starting from `A`.
+    fn sequence_cost(&mut self, sequence: &[char], depth: usize) -> u64 {
+        iter::once(&'A')
+            .chain(sequence)
+            .zip(sequence)
+            .map(|(&from, &to)| self.directional_cost(from, to, depth))
+            .sum()
+    }
+
+    fn directional_cost(&mut self, from: char, to: char, depth: usize) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        if let Some(&cost) = self.costs.get(&(from, to, depth)) {
+            return cost;
+        }
+
+        let cost = Keypad::DIRECTIONAL
+            .move_sequences(from, to)
+            .iter()
+            .map(|sequence| self.sequence_cost(sequence, depth - 1))
+            .min()
+            .expect("every key can be reached");
+        self.costs.insert((from, to, depth), cost);
+        cost
+    }
+
+    /// The length of the shortest sequence the human presses to type `code` on the numeric keypad.
+    fn code_cost(&mut self, code: &[char]) -> u64 {
+        let mut previous = 'A';
+        let mut cost = 0;
+        for &key in code {
+            cost += Keypad::NUMERIC
+                .move_sequences(previous, key)
+                .iter()
+                .map(|sequence| self.sequence_cost(sequence, self.robots))
+                .min()
+                .expect("every key can be reached");
+            previous = key;
+        }
+        cost
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Code {
+    keys: Vec<char>,
+    numeric_part: u64,
+}
+
+fn parse_code(line: Span) -> Result<Code, ParseError> {
+    let keys = line
+        .chars()
+        .map(|(key, span)| match key {
+            '0'..='9' | 'A' => Ok(key),
+            _ => Err(span.error("a digit or `A`")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if keys.last() != Some(&'A') {
+        return Err(line.end().error("`A` at the end of the code"));
+    }
+    let digits = line.slice(0, line.text().len() - 1);
+
+    Ok(Code {
+        keys,
+        numeric_part: digits.parse("digits followed by `A`")?,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Code>, ParseError> {
+    Span::new(input).lines().map(parse_code).collect()
+}
+
+fn calculate_complexity_sum(codes: &[Code], robots: usize) -> u64 {
+    let mut chain = KeypadChain::new(robots);
+    codes
+        .iter()
+        .map(|code| chain.code_cost(&code.keys) * code.numeric_part)
+        .sum()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = Vec<Code>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: include_str!("../inputs/examples/day_21.txt"),
+        part_one: Some("126384"),
+        part_two: None,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(codes: &Self::Input) -> Option<Answer> {
+        Some(calculate_complexity_sum(codes, 2).into())
+    }
+
+    fn part_two(codes: &Self::Input) -> Option<Answer> {
+        Some(calculate_complexity_sum(codes, 25).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let codes = parse_input(Day21::EXAMPLES[0].input).unwrap();
+        assert_eq!(codes.len(), 5);
+        assert_eq!(
+            codes[0],
+            Code {
+                keys: vec!['0', '2', '9', 'A'],
+                numeric_part: 29
+            }
+        );
+        assert_eq!(
+            parse_input("02xA").unwrap_err().to_string(),
+            "line 1, column 3: expected a digit or `A`, found `x`"
+        );
+        assert_eq!(
+            parse_input("029").unwrap_err().to_string(),
+            "line 1, column 4: expected `A` at the end of the code, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_move_sequences_avoid_the_gap() {
+        let sequences = |keypad: &Keypad, from, to| {
+            keypad
+                .move_sequences(from, to)
+                .into_iter()
+                .map(String::from_iter)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(sequences(&Keypad::NUMERIC, 'A', '1'), vec!["^<<A"]);
+        assert_eq!(sequences(&Keypad::NUMERIC, '7', '0'), vec![">vvvA"]);
+        assert_eq!(sequences(&Keypad::NUMERIC, '2', '9'), vec![">^^A", "^^>A"]);
+        assert_eq!(sequences(&Keypad::NUMERIC, '5', '5'), vec!["A"]);
+        assert_eq!(sequences(&Keypad::DIRECTIONAL, '<', '^'), vec![">^A"]);
+        assert_eq!(sequences(&Keypad::DIRECTIONAL, 'A', '<'), vec!["v<<A"]);
+    }
+
+    #[test]
+    fn test_code_cost() {
+        let mut chain = KeypadChain::new(2);
+        let costs = parse_input(Day21::EXAMPLES[0].input)
+            .unwrap()
+            .iter()
+            .map(|code| chain.code_cost(&code.keys))
+            .collect::<Vec<_>>();
+        assert_eq!(costs, vec![68, 60, 68, 64, 64]);
+
+        assert_eq!(KeypadChain::new(0).code_cost(&['0', '2', '9', 'A']), 12);
+    }
+
+    #[test]
+    fn test_calculate_complexity_sum() {
+        let codes = parse_input(Day21::EXAMPLES[0].input).unwrap();
+        assert_eq!(calculate_complexity_sum(&codes, 2), 126384);
+        // The puzzle description gives no part two answer for the example. This is the value the
+        // solution has always produced, kept to catch regressions.
+        assert_eq!(calculate_complexity_sum(&codes, 25), 154115708116294);
+    }
+}
@@ -0,0 +1,182 @@
+use std::num::NonZeroUsize;
+use std::thread;
+
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Example, Solution};
+
+const SECRETS_PER_DAY: usize = 2000;
+
+/// Price changes lie in `-9..=9`, so a window of four changes is a four-digit base-19 number.
+const CHANGE_VALUES: usize = 19;
+const WINDOWS: usize = CHANGE_VALUES.pow(4);
+
+/// Secret numbers are kept below this, so they always fit into 24 bits.
+const PRUNE: u64 = 16_777_216;
+
+/// Initial secrets must already be pruned, otherwise the first step of [`next_secret`] could
+/// overflow.
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    const EXPECTED: &str = "an initial secret number below 16777216";
+    Span::new(input)
+        .lines()
+        .map(|line| match line.parse(EXPECTED)? {
+            secret @ 0..PRUNE => Ok(secret),
+            _ => Err(line.error(EXPECTED)),
+        })
+        .collect()
+}
+
+fn next_secret(secret: u64) -> u64 {
+    let secret = ((secret * 64) ^ secret) % PRUNE;
+    let secret = ((secret / 32) ^ secret) % PRUNE;
+    ((secret * 2048) ^ secret) % PRUNE
+}
+
+fn secrets(initial: u64) -> impl Iterator<Item = u64> {
+    std::iter::successors(Some(initial), |&secret| Some(next_secret(secret)))
+}
+
+fn sum_final_secrets(initial_secrets: &[u64]) -> u64 {
+    initial_secrets
+        .iter()
+        .map(|&initial| secrets(initial).nth(SECRETS_PER_DAY).unwrap_or_default())
+        .sum()
+}
+
+/// Adds the price each buyer sells at for every window of four price changes, indexed by the
+/// encoded window. A buyer sells the first time a window appears, so later repeats are skipped.
+fn add_window_prices(initial_secrets: &[u64], totals: &mut [u32]) {
+    // The index of the last buyer that saw each window, offset by one so that zero means none.
+    let mut last_seen = vec![0; WINDOWS];
+
+    for (buyer, &initial) in initial_secrets.iter().enumerate() {
+        let mut window = 0;
+        let mut previous_price = initial % 10;
+        for (index, secret) in secrets(initial).skip(1).take(SECRETS_PER_DAY).enumerate() {
+            let price = secret % 10;
+            let change = (price + 9 - previous_price) as usize;
+            window = (window * CHANGE_VALUES + change) % WINDOWS;
+            previous_price = price;
+
+            if index >= 3 && last_seen[window] != buyer + 1 {
+                last_seen[window] = buyer + 1;
+                totals[window] += price as u32;
+            }
+        }
+    }
+}
+
+/// The most bananas a single window of four price changes can buy. The buyers are split between
+/// threads that each fill their own totals, which are then added up.
+fn find_most_bananas(initial_secrets: &[u64]) -> u32 {
+    let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let chunk_size = initial_secrets.len().div_ceil(threads).max(1);
+
+    let totals = thread::scope(|scope| {
+        let workers = initial_secrets
+            .chunks(chunk_size)
+            .map(|buyers| {
+                scope.spawn(move || {
+                    let mut totals = vec![0; WINDOWS];
+                    add_window_prices(buyers, &mut totals);
+                    totals
+                })
+            })
+            .collect::<Vec<_>>();
+
+        workers
+            .into_iter()
+            .fold(vec![0; WINDOWS], |mut totals, worker| {
+                let worker_totals = worker.join().expect("worker threads do not panic");
+                for (total, worker_total) in totals.iter_mut().zip(worker_totals) {
+                    *total += worker_total;
+                }
+                totals
+            })
+    });
+
+    totals.into_iter().max().unwrap_or_default()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = Vec<u64>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../inputs/examples/day_22.txt"),
+            part_one: Some("37327623"),
+            part_two: None,
+        },
+        Example {
+            name: "part two",
+            input: include_str!("../inputs/examples/day_22_part_two.txt"),
+            part_one: None,
+            part_two: Some("23"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(initial_secrets: &Self::Input) -> Option<Answer> {
+        Some(sum_final_secrets(initial_secrets).into())
+    }
+
+    fn part_two(initial_secrets: &Self::Input) -> Option<Answer> {
+        Some(find_most_bananas(initial_secrets).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("1\n16777215"), Ok(vec![1, 16777215]));
+        assert_eq!(
+            parse_input("1\n16777216").unwrap_err().to_string(),
+            "line 2, column 1: expected an initial secret number below 16777216, found `16777216`"
+        );
+        assert_eq!(
+            parse_input("288230376151711744").unwrap_err().to_string(),
+            "line 1, column 1: expected an initial secret number below 16777216, \
+             found `288230376151711744`"
+        );
+    }
+
+    #[test]
+    fn test_next_secret() {
+        assert_eq!(
+            secrets(123).skip(1).take(10).collect::<Vec<_>>(),
+            vec![
+                15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+                5908254
+            ]
+        );
+        assert_eq!(secrets(2024).nth(2000), Some(8667524));
+    }
+
+    #[test]
+    fn test_add_window_prices() {
+        let mut totals = vec![0; WINDOWS];
+        add_window_prices(&[123], &mut totals);
+        // The changes -1,-1,0,2 end at the price 6 in the puzzle's ten-secret walkthrough.
+        let window = [-1, -1, 0, 2].iter().fold(0, |window, change: &i32| {
+            window * CHANGE_VALUES + (change + 9) as usize
+        });
+        assert_eq!(totals[window], 6);
+    }
+
+    #[test]
+    fn test_find_most_bananas() {
+        assert_eq!(find_most_bananas(&[1, 2, 3, 2024]), 23);
+        assert_eq!(find_most_bananas(&[]), 0);
+    }
+}
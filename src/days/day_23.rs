@@ -0,0 +1,110 @@
+use crate::graph::Graph;
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Example, Solution};
+
+fn parse_computer(name: Span) -> Result<String, ParseError> {
+    if !name.is_empty() && name.text().chars().all(|c| c.is_ascii_lowercase()) {
+        Ok(name.text().to_string())
+    } else {
+        Err(name.error("a computer name of lowercase letters"))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Graph<String>, ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|line| {
+            let (a, b) = line.split_once("-", "two computers separated by `-`")?;
+            let (a, b) = (parse_computer(a)?, parse_computer(b)?);
+            if a == b {
+                return Err(line.error("two different computers"));
+            }
+            Ok((a, b))
+        })
+        .collect()
+}
+
+/// Every set of three connected computers once, with its names in ascending order.
+fn find_triangles(network: &Graph<String>) -> impl Iterator<Item = [&String; 3]> {
+    network.edges().flat_map(move |(a, b)| {
+        network
+            .neighbours(b)
+            .filter(move |c| b < *c && network.contains_edge(a, c))
+            .map(move |c| [a, b, c])
+    })
+}
+
+fn count_chief_triangles(network: &Graph<String>) -> usize {
+    find_triangles(network)
+        .filter(|triangle| triangle.iter().any(|computer| computer.starts_with('t')))
+        .count()
+}
+
+fn find_password(network: &Graph<String>) -> String {
+    let mut party = network.maximum_clique();
+    party.sort();
+    party.join(",")
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Graph<String>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: include_str!("../inputs/examples/day_23.txt"),
+        part_one: Some("7"),
+        part_two: Some("co,de,ka,ta"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(network: &Self::Input) -> Option<Answer> {
+        Some(count_chief_triangles(network).into())
+    }
+
+    fn part_two(network: &Self::Input) -> Option<Answer> {
+        Some(find_password(network).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let network = parse_input(Day23::EXAMPLES[0].input).unwrap();
+        assert_eq!(network.node_count(), 16);
+        assert!(network.contains_edge(&"tc".to_string(), &"kh".to_string()));
+        assert_eq!(
+            parse_input("kh-tc\nqp kh").unwrap_err().to_string(),
+            "line 2, column 1: expected two computers separated by `-`, found `qp kh`"
+        );
+        assert_eq!(
+            parse_input("kh-Tc").unwrap_err().to_string(),
+            "line 1, column 4: expected a computer name of lowercase letters, found `Tc`"
+        );
+        assert_eq!(
+            parse_input("kh-tc\nab-ab").unwrap_err().to_string(),
+            "line 2, column 1: expected two different computers, found `ab-ab`"
+        );
+    }
+
+    #[test]
+    fn test_find_triangles() {
+        let network = parse_input(Day23::EXAMPLES[0].input).unwrap();
+        let mut triangles = find_triangles(&network)
+            .map(|triangle| triangle.map(String::as_str).join(","))
+            .collect::<Vec<_>>();
+        triangles.sort();
+        assert_eq!(triangles.len(), 12);
+        assert_eq!(triangles[..3], ["aq,cg,yn", "aq,vc,wq", "co,de,ka"]);
+        assert_eq!(count_chief_triangles(&network), 7);
+    }
+}
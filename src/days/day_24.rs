@@ -0,0 +1,454 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Example, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operation {
+    And,
+    Or,
+    Xor,
+}
+
+impl Operation {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Self::And => a && b,
+            Self::Or => a || b,
+            Self::Xor => a ^ b,
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::And => write!(f, "AND"),
+            Self::Or => write!(f, "OR"),
+            Self::Xor => write!(f, "XOR"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Gate {
+    inputs: [String; 2],
+    operation: Operation,
+    output: String,
+}
+
+impl Gate {
+    fn has_input_bits(&self) -> bool {
+        self.inputs
+            .iter()
+            .all(|input| input.starts_with('x') || input.starts_with('y'))
+    }
+
+    fn has_first_input_bits(&self) -> bool {
+        self.inputs
+            .iter()
+            .all(|input| input == "x00" || input == "y00")
+    }
+}
+
+impl fmt::Display for Gate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b] = &self.inputs;
+        write!(f, "{a} {} {b} -> {}", self.operation, self.output)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Device {
+    initial_values: Vec<(String, bool)>,
+    gates: Vec<Gate>,
+}
+
+fn parse_wire(wire: Span) -> Result<String, ParseError> {
+    if !wire.is_empty() && wire.text().chars().all(|c| c.is_ascii_alphanumeric()) {
+        Ok(wire.text().to_string())
+    } else {
+        Err(wire.error("a wire name"))
+    }
+}
+
+fn parse_initial_value(line: Span) -> Result<(String, bool), ParseError> {
+    let (wire, value) = line.split_once(": ", "a wire and its value separated by `: `")?;
+    let value = match value.text() {
+        "0" => false,
+        "1" => true,
+        _ => return Err(value.error("`0` or `1`")),
+    };
+    Ok((parse_wire(wire)?, value))
+}
+
+fn parse_gate(line: Span) -> Result<Gate, ParseError> {
+    let (expression, output) = line.split_once(" -> ", "a gate and its output wire")?;
+    let [a, operation, b] = expression.split_whitespace().collect::<Vec<_>>()[..] else {
+        return Err(expression.error("two input wires around `AND`, `OR` or `XOR`"));
+    };
+    let operation = match operation.text() {
+        "AND" => Operation::And,
+        "OR" => Operation::Or,
+        "XOR" => Operation::Xor,
+        _ => return Err(operation.error("`AND`, `OR` or `XOR`")),
+    };
+
+    Ok(Gate {
+        inputs: [parse_wire(a)?, parse_wire(b)?],
+        operation,
+        output: parse_wire(output)?,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Device, ParseError> {
+    let input = Span::new(input);
+    let [initial_values, gates] = input.blocks()[..] else {
+        return Err(input
+            .end()
+            .error("initial wire values and gates, separated by a blank line"));
+    };
+
+    Ok(Device {
+        initial_values: initial_values
+            .lines()
+            .map(parse_initial_value)
+            .collect::<Result<_, _>>()?,
+        gates: gates.lines().map(parse_gate).collect::<Result<_, _>>()?,
+    })
+}
+
+/// The value of every wire, or `None` if some gates never get both of their inputs.
+fn simulate(device: &Device) -> Option<HashMap<&str, bool>> {
+    let mut values = device
+        .initial_values
+        .iter()
+        .map(|(wire, value)| (wire.as_str(), *value))
+        .collect::<HashMap<_, _>>();
+    let mut pending = device.gates.iter().collect::<Vec<_>>();
+
+    while !pending.is_empty() {
+        let before = pending.len();
+        pending.retain(|gate| {
+            let [a, b] = &gate.inputs;
+            match (values.get(a.as_str()), values.get(b.as_str())) {
+                (Some(&a), Some(&b)) => {
+                    values.insert(&gate.output, gate.operation.apply(a, b));
+                    false
+                }
+                _ => true,
+            }
+        });
+        if pending.len() == before {
+            return None;
+        }
+    }
+
+    Some(values)
+}
+
+/// The number whose bits are on the wires starting with `prefix`, `z00` being the lowest bit.
+fn read_number(values: &HashMap<&str, bool>, prefix: char) -> u64 {
+    values
+        .iter()
+        .filter(|(&wire, &value)| wire.starts_with(prefix) && value)
+        .filter_map(|(wire, _)| wire[1..].parse::<u32>().ok())
+        .map(|bit| 1 << bit)
+        .sum()
+}
+
+/// A structural rule of a ripple-carry adder. Bit `i` computes `s = x XOR y` and `a = x AND y`,
+/// then `z = s XOR carry` and `b = s AND carry`, and passes `a OR b` on as the next carry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Rule {
+    OutputFromXor,
+    LastOutputFromOr,
+    CarryXorToOutput,
+    InputXorToXor,
+    AndToOr,
+    CarryOrToXor,
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutputFromXor => write!(f, "output bits except the last come from XOR gates"),
+            Self::LastOutputFromOr => write!(f, "the last output bit is the final carry OR gate"),
+            Self::CarryXorToOutput => {
+                write!(f, "XOR gates that do not read input bits set output bits")
+            }
+            Self::InputXorToXor => write!(f, "XOR gates of input bits feed an XOR gate"),
+            Self::AndToOr => write!(f, "AND gates except the first bit's feed only OR gates"),
+            Self::CarryOrToXor => write!(f, "OR gates pass their carry on to an XOR gate"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Fault<'a> {
+    gate: &'a Gate,
+    rule: Rule,
+}
+
+impl fmt::Display for Fault<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` breaks the rule that {}", self.gate, self.rule)
+    }
+}
+
+fn check_gate(gate: &Gate, readers: &[&Gate], last_output: &str) -> Option<Rule> {
+    let is_output = gate.output.starts_with('z');
+    let feeds_only =
+        |operation| !readers.is_empty() && readers.iter().all(|r| r.operation == operation);
+    let feeds_any = |operation| readers.iter().any(|r| r.operation == operation);
+
+    match gate.operation {
+        _ if gate.output == last_output => {
+            (gate.operation != Operation::Or).then_some(Rule::LastOutputFromOr)
+        }
+        Operation::And | Operation::Or if is_output => Some(Rule::OutputFromXor),
+        Operation::Xor if !gate.has_input_bits() => (!is_output).then_some(Rule::CarryXorToOutput),
+        Operation::Xor if gate.has_first_input_bits() => None,
+        Operation::Xor => (!feeds_any(Operation::Xor)).then_some(Rule::InputXorToXor),
+        Operation::And if gate.has_first_input_bits() => None,
+        Operation::And => (!feeds_only(Operation::Or)).then_some(Rule::AndToOr),
+        Operation::Or => (!feeds_any(Operation::Xor)).then_some(Rule::CarryOrToXor),
+    }
+}
+
+/// Checks every gate against the structure of a ripple-carry adder. The outputs of the gates that
+/// break a rule are the swapped wires.
+fn find_faults(device: &Device) -> Vec<Fault<'_>> {
+    let mut readers: HashMap<&str, Vec<&Gate>> = HashMap::new();
+    for gate in &device.gates {
+        for input in &gate.inputs {
+            readers.entry(input).or_default().push(gate);
+        }
+    }
+    let last_output = device
+        .gates
+        .iter()
+        .map(|gate| &gate.output)
+        .filter(|output| output.starts_with('z'))
+        .max()
+        .map_or("", String::as_str);
+
+    device
+        .gates
+        .iter()
+        .filter_map(|gate| {
+            let gate_readers = readers
+                .get(gate.output.as_str())
+                .map_or(&[][..], Vec::as_slice);
+            let rule = check_gate(gate, gate_readers, last_output)?;
+            Some(Fault { gate, rule })
+        })
+        .collect()
+}
+
+/// The number of gate output pairs that are swapped in the puzzle's device.
+const SWAPPED_PAIRS: usize = 4;
+
+/// Whether the device has the shape of a ripple-carry adder of two `n`-bit numbers: `n` bits on
+/// each of `x` and `y`, `n + 1` output bits, and five gates per bit except for the first, which
+/// only needs two.
+fn is_adder(device: &Device) -> bool {
+    let count = |prefix| {
+        device
+            .initial_values
+            .iter()
+            .filter(|(wire, _)| wire.starts_with(prefix))
+            .count()
+    };
+    let bits = count('x');
+    let outputs = device
+        .gates
+        .iter()
+        .filter(|gate| gate.output.starts_with('z'))
+        .count();
+    bits >= 2 && count('y') == bits && outputs == bits + 1 && device.gates.len() == 5 * bits - 3
+}
+
+/// The swapped wires of a device and the faults that gave them away.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Swaps<'a> {
+    /// The sorted outputs of the faulty gates, separated by commas.
+    wires: String,
+    faults: Vec<Fault<'a>>,
+}
+
+/// The swaps, if the device is an adder and the outputs of the faulty gates make up exactly
+/// `pairs` swapped pairs. Anything else means the rules did not pin down the swaps.
+fn find_swapped_wires(device: &Device, pairs: usize) -> Option<Swaps<'_>> {
+    if !is_adder(device) {
+        return None;
+    }
+    let faults = find_faults(device);
+    let mut wires = faults
+        .iter()
+        .map(|fault| fault.gate.output.as_str())
+        .collect::<Vec<_>>();
+    wires.sort();
+    wires.dedup();
+    (wires.len() == 2 * pairs).then(|| Swaps {
+        wires: wires.join(","),
+        faults,
+    })
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Device;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../inputs/examples/day_24.txt"),
+            part_one: Some("2024"),
+            part_two: None,
+        },
+        Example {
+            name: "small",
+            input: include_str!("../inputs/examples/day_24_small.txt"),
+            part_one: Some("4"),
+            part_two: None,
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(device: &Self::Input) -> Option<Answer> {
+        let values = simulate(device)?;
+        Some(read_number(&values, 'z').into())
+    }
+
+    fn part_two(device: &Self::Input) -> Option<Answer> {
+        find_swapped_wires(device, SWAPPED_PAIRS).map(|swaps| swaps.wires.into())
+    }
+
+    fn part_two_notes(device: &Self::Input) -> Vec<String> {
+        find_swapped_wires(device, SWAPPED_PAIRS).map_or_else(Vec::new, |swaps| {
+            swaps.faults.iter().map(Fault::to_string).collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A correct ripple-carry adder of `bits` bits, adding `x` and `y`.
+    fn adder(bits: usize, x: u64, y: u64) -> String {
+        let mut lines = Vec::new();
+        for bit in 0..bits {
+            lines.push(format!("x{bit:02}: {}", x >> bit & 1));
+        }
+        for bit in 0..bits {
+            lines.push(format!("y{bit:02}: {}", y >> bit & 1));
+        }
+        lines.push(String::new());
+        lines.push("x00 XOR y00 -> z00".to_string());
+        lines.push("x00 AND y00 -> c00".to_string());
+        for bit in 1..bits {
+            let carry = if bit == bits - 1 {
+                format!("z{bits:02}")
+            } else {
+                format!("c{bit:02}")
+            };
+            lines.extend([
+                format!("x{bit:02} XOR y{bit:02} -> s{bit:02}"),
+                format!("x{bit:02} AND y{bit:02} -> a{bit:02}"),
+                format!("s{bit:02} XOR c{:02} -> z{bit:02}", bit - 1),
+                format!("s{bit:02} AND c{:02} -> b{bit:02}", bit - 1),
+                format!("a{bit:02} OR b{bit:02} -> {carry}"),
+            ]);
+        }
+        lines.join("\n")
+    }
+
+    fn swap_outputs(input: &str, a: &str, b: &str) -> String {
+        input
+            .replace(&format!("-> {a}"), "-> swapped")
+            .replace(&format!("-> {b}"), &format!("-> {a}"))
+            .replace("-> swapped", &format!("-> {b}"))
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let device = parse_input(Day24::EXAMPLES[1].input).unwrap();
+        assert_eq!(device.initial_values[0], ("x00".to_string(), true));
+        assert_eq!(device.gates[2].to_string(), "x02 OR y02 -> z02");
+        assert_eq!(
+            parse_input("x00: 1\n\nx00 NAND y00 -> z00")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 5: expected `AND`, `OR` or `XOR`, found `NAND`"
+        );
+        assert_eq!(
+            parse_input("x00: 2\n\nx00 AND y00 -> z00")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 6: expected `0` or `1`, found `2`"
+        );
+    }
+
+    #[test]
+    fn test_simulate() {
+        let device = parse_input(&adder(6, 45, 27)).unwrap();
+        let values = simulate(&device).unwrap();
+        assert_eq!(read_number(&values, 'x'), 45);
+        assert_eq!(read_number(&values, 'z'), 72);
+
+        let device = parse_input("x00: 1\n\nx00 AND c00 -> z00").unwrap();
+        assert_eq!(simulate(&device), None);
+    }
+
+    #[test]
+    fn test_find_faults() {
+        let device = parse_input(&adder(6, 0, 0)).unwrap();
+        assert_eq!(find_faults(&device), vec![]);
+
+        let device = parse_input(&swap_outputs(&adder(6, 0, 0), "z02", "a02")).unwrap();
+        let faults = find_faults(&device)
+            .iter()
+            .map(Fault::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            faults,
+            vec![
+                "`x02 AND y02 -> z02` breaks the rule that output bits except the last come \
+                 from XOR gates",
+                "`s02 XOR c01 -> a02` breaks the rule that XOR gates that do not read input \
+                 bits set output bits",
+            ]
+        );
+        let swaps = find_swapped_wires(&device, 1).unwrap();
+        assert_eq!(swaps.wires, "a02,z02");
+        assert_eq!(swaps.faults, find_faults(&device));
+        assert_eq!(find_swapped_wires(&device, SWAPPED_PAIRS), None);
+
+        let swapped = swap_outputs(&adder(6, 0, 0), "s03", "a03");
+        let swapped = swap_outputs(&swapped, "z06", "b04");
+        let device = parse_input(&swapped).unwrap();
+        assert_eq!(
+            find_swapped_wires(&device, 2).map(|swaps| swaps.wires),
+            Some("a03,b04,s03,z06".to_string())
+        );
+    }
+
+    #[test]
+    fn test_find_swapped_wires_of_other_devices() {
+        assert!(is_adder(&parse_input(&adder(6, 0, 0)).unwrap()));
+        for example in Day24::EXAMPLES {
+            let device = parse_input(example.input).unwrap();
+            assert!(!is_adder(&device), "{}", example.name);
+            assert_eq!(find_swapped_wires(&device, SWAPPED_PAIRS), None);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Example, Solution};
+
+const COLUMNS: usize = 5;
+const ROWS: usize = 7;
+/// The rows between the filled edge row and the empty one, which the pins can reach into.
+const PIN_SPACE: usize = ROWS - 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    /// Pins hang down from a filled top row.
+    Lock,
+    /// Pins stand up from a filled bottom row.
+    Key,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schematic {
+    kind: Kind,
+    heights: [usize; COLUMNS],
+}
+
+impl Schematic {
+    /// The cells of the pin space the pins fill, one bit per cell by row from the top. A lock and
+    /// a key fit together exactly if their masks do not overlap.
+    fn mask(&self) -> u32 {
+        let mut mask = 0;
+        for (column, &height) in self.heights.iter().enumerate() {
+            for level in 0..height {
+                let row = match self.kind {
+                    Kind::Lock => level,
+                    Kind::Key => PIN_SPACE - 1 - level,
+                };
+                mask |= 1 << (row * COLUMNS + column);
+            }
+        }
+        mask
+    }
+}
+
+fn parse_row(row: Span) -> Result<Vec<(bool, Span)>, ParseError> {
+    let cells = row
+        .chars()
+        .map(|(cell, span)| match cell {
+            '#' => Ok((true, span)),
+            '.' => Ok((false, span)),
+            _ => Err(span.error("`#` or `.`")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if cells.len() != COLUMNS {
+        return Err(row.error("a row of 5 cells"));
+    }
+    Ok(cells)
+}
+
+fn parse_schematic(block: Span) -> Result<Schematic, ParseError> {
+    let mut rows = block
+        .lines()
+        .map(parse_row)
+        .collect::<Result<Vec<_>, _>>()?;
+    if rows.len() != ROWS {
+        return Err(block.end().error("a schematic of 7 rows"));
+    }
+
+    let is_filled = |row: &[(bool, Span)]| row.iter().all(|&(filled, _)| filled);
+    let is_empty = |row: &[(bool, Span)]| row.iter().all(|&(filled, _)| !filled);
+    let kind = if is_filled(&rows[0]) && is_empty(&rows[ROWS - 1]) {
+        Kind::Lock
+    } else if is_empty(&rows[0]) && is_filled(&rows[ROWS - 1]) {
+        // Walk the key's pins from their base, like the lock's.
+        rows.reverse();
+        Kind::Key
+    } else {
+        return Err(block
+            .lines()
+            .next()
+            .unwrap_or(block)
+            .error("a lock with a filled top row or a key with a filled bottom row"));
+    };
+
+    let mut heights = [0; COLUMNS];
+    for (column, height) in heights.iter_mut().enumerate() {
+        let mut cells = rows[1..].iter().map(|row| row[column]);
+        *height = cells.by_ref().take_while(|&(filled, _)| filled).count();
+        if let Some((_, span)) = cells.find(|&(filled, _)| filled) {
+            return Err(span.error("`.` after the end of the pin"));
+        }
+    }
+
+    Ok(Schematic { kind, heights })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Schematic>, ParseError> {
+    Span::new(input)
+        .blocks()
+        .into_iter()
+        .map(parse_schematic)
+        .collect()
+}
+
+/// Counts the lock and key pairs whose pins do not overlap. Schematics with the same pin heights
+/// are bucketed first, so each distinct lock is only tested against each distinct key once.
+fn count_fitting_pairs(schematics: &[Schematic]) -> usize {
+    let mut locks = HashMap::<u32, usize>::new();
+    let mut keys = HashMap::<u32, usize>::new();
+    for schematic in schematics {
+        let buckets = match schematic.kind {
+            Kind::Lock => &mut locks,
+            Kind::Key => &mut keys,
+        };
+        *buckets.entry(schematic.mask()).or_default() += 1;
+    }
+
+    locks
+        .iter()
+        .flat_map(|(lock, lock_count)| {
+            keys.iter()
+                .filter(move |(key, _)| lock & *key == 0)
+                .map(move |(_, key_count)| lock_count * key_count)
+        })
+        .sum()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = Vec<Schematic>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: include_str!("../inputs/examples/day_25.txt"),
+        part_one: Some("3"),
+        part_two: None,
+    }];
+
+    const HAS_PART_TWO: bool = false;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(schematics: &Self::Input) -> Option<Answer> {
+        Some(count_fitting_pairs(schematics).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let schematics = parse_input(Day25::EXAMPLES[0].input).unwrap();
+        assert_eq!(schematics.len(), 5);
+        assert_eq!(
+            schematics[0],
+            Schematic {
+                kind: Kind::Lock,
+                heights: [0, 5, 3, 4, 3]
+            }
+        );
+        assert_eq!(
+            schematics[2],
+            Schematic {
+                kind: Kind::Key,
+                heights: [5, 0, 2, 1, 3]
+            }
+        );
+
+        assert_eq!(
+            parse_input("#####\n.....\n.....\n.....\n.....\n.....\n#####")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 1: expected a lock with a filled top row or a key with a filled \
+             bottom row, found `#####`"
+        );
+        assert_eq!(
+            parse_input("#####\n.#...\n.....\n.#...\n.....\n.....\n.....")
+                .unwrap_err()
+                .to_string(),
+            "line 4, column 2: expected `.` after the end of the pin, found `#`"
+        );
+        assert_eq!(
+            parse_input("#####\n.....").unwrap_err().to_string(),
+            "line 2, column 6: expected a schematic of 7 rows, found end of line"
+        );
+        assert_eq!(
+            parse_input("#####\n..x..").unwrap_err().to_string(),
+            "line 2, column 3: expected `#` or `.`, found `x`"
+        );
+    }
+
+    #[test]
+    fn test_count_fitting_pairs() {
+        let schematics = parse_input(Day25::EXAMPLES[0].input).unwrap();
+        // The pins overlap exactly where the heights of a column add up to more than 5.
+        for lock in schematics.iter().filter(|s| s.kind == Kind::Lock) {
+            for key in schematics.iter().filter(|s| s.kind == Kind::Key) {
+                let overlaps = lock
+                    .heights
+                    .iter()
+                    .zip(key.heights)
+                    .any(|(lock_height, key_height)| lock_height + key_height > PIN_SPACE);
+                assert_eq!(lock.mask() & key.mask() != 0, overlaps);
+            }
+        }
+        assert_eq!(count_fitting_pairs(&schematics), 3);
+
+        // Duplicates count as separate pairs.
+        let doubled = [schematics.clone(), schematics].concat();
+        assert_eq!(count_fitting_pairs(&doubled), 12);
+    }
+}
@@ -0,0 +1,137 @@
+use regex::Regex;
+
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Example, Solution};
+
+#[derive(Debug, PartialEq)]
+pub enum Instruction {
+    Multiply(i32, i32),
+    Do,
+    DoNot,
+}
+
+impl Instruction {
+    fn new(instruction_code: Span) -> Result<Self, ParseError> {
+        if instruction_code.text().starts_with("mul") {
+            let (x, y) = instruction_code
+                .strip_prefix("mul(")?
+                .split_once(",", "two factors separated by `,`")?;
+            let (y, _) = y.split_once(")", "`)`")?;
+            Ok(Self::Multiply(x.parse("a factor")?, y.parse("a factor")?))
+        } else if instruction_code.text().starts_with("don't") {
+            Ok(Self::DoNot)
+        } else if instruction_code.text().starts_with("do") {
+            Ok(Self::Do)
+        } else {
+            Err(instruction_code.error("`mul(X,Y)`, `do()` or `don't()`"))
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Vec<Span<'_>> {
+    let multiply_instruction_regex = Regex::new(r"mul\(\d+,\d+\)|do(?:n't)?\(\)").unwrap();
+    let input = Span::new(input);
+    multiply_instruction_regex
+        .find_iter(input.text())
+        .map(|instruction_match| input.slice(instruction_match.start(), instruction_match.end()))
+        .collect()
+}
+
+fn execute_program(program: &[Instruction], handle_enabling: bool) -> i32 {
+    program
+        .iter()
+        .fold(
+            (0, true),
+            |(sum, is_enabled), instruction| match instruction {
+                Instruction::Multiply(x, y) if is_enabled => (sum + x * y, true),
+                Instruction::Do if handle_enabling && !is_enabled => (sum, true),
+                Instruction::DoNot if handle_enabling && is_enabled => (sum, false),
+                _ => (sum, is_enabled),
+            },
+        )
+        .0
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Instruction>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "part one",
+            input: include_str!("../inputs/examples/day_3_part_one.txt"),
+            part_one: Some("161"),
+            part_two: None,
+        },
+        Example {
+            name: "part two",
+            input: include_str!("../inputs/examples/day_3_part_two.txt"),
+            part_one: None,
+            part_two: Some("48"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+            .into_iter()
+            .map(Instruction::new)
+            .collect()
+    }
+
+    fn part_one(program: &Self::Input) -> Option<Answer> {
+        Some(execute_program(program, false).into())
+    }
+
+    fn part_two(program: &Self::Input) -> Option<Answer> {
+        Some(execute_program(program, true).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            parse_input("Amul(1,2)Bdo()Cdon't()D")
+                .iter()
+                .map(|instruction_code| instruction_code.text())
+                .collect::<Vec<&str>>(),
+            vec!["mul(1,2)", "do()", "don't()"]
+        );
+    }
+
+    #[test]
+    fn test_new_instruction() {
+        assert_eq!(
+            Instruction::new("mul(1,2)".into()),
+            Ok(Instruction::Multiply(1, 2))
+        );
+        assert_eq!(Instruction::new("do()".into()), Ok(Instruction::Do));
+        assert_eq!(Instruction::new("don't()".into()), Ok(Instruction::DoNot));
+        assert_eq!(
+            Instruction::new("mul(1,99999999999)".into())
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 7: expected a factor, found `99999999999`"
+        );
+    }
+
+    #[test]
+    fn test_execute_program() {
+        let program = vec![
+            Instruction::Multiply(1, 2),
+            Instruction::DoNot,
+            Instruction::Multiply(3, 4),
+            Instruction::Do,
+            Instruction::Multiply(5, 6),
+        ];
+
+        assert_eq!(execute_program(&program, false), 44);
+        assert_eq!(execute_program(&program, true), 32);
+    }
+}
@@ -0,0 +1,176 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Example, Solution};
+
+type PageOrderRules = HashMap<i32, HashSet<i32>>;
+
+fn parse_input(input: &str) -> Result<(PageOrderRules, Vec<Vec<i32>>), ParseError> {
+    let input = Span::new(input);
+    let [page_order_rules_input, page_positions_input] = input.blocks()[..] else {
+        return Err(input
+            .end()
+            .error("page ordering rules and updates, separated by a blank line"));
+    };
+
+    let page_order_specifications = page_order_rules_input
+        .lines()
+        .map(|line| {
+            let (first, second) = line.split_once("|", "two pages separated by `|`")?;
+            Ok((
+                first.parse("a page number")?,
+                second.parse("a page number")?,
+            ))
+        })
+        .collect::<Result<Vec<(i32, i32)>, ParseError>>()?;
+    let mut page_order_rules: HashMap<i32, HashSet<i32>> = HashMap::new();
+    for page_order_specification in page_order_specifications {
+        if let Some(pages) = page_order_rules.get_mut(&page_order_specification.0) {
+            pages.insert(page_order_specification.1);
+        } else {
+            page_order_rules.insert(
+                page_order_specification.0,
+                HashSet::from([page_order_specification.1]),
+            );
+        }
+    }
+
+    let page_orders = page_positions_input
+        .lines()
+        .map(|line| {
+            line.split(',')
+                .map(|page| page.parse("a page number"))
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((page_order_rules, page_orders))
+}
+
+fn map_page_positions(page_order: &[i32]) -> HashMap<i32, usize> {
+    page_order
+        .iter()
+        .enumerate()
+        .map(|(position, page)| (*page, position))
+        .collect()
+}
+
+fn is_valid_page_order(page_order_rules: &HashMap<i32, HashSet<i32>>, page_order: &[i32]) -> bool {
+    let page_positions = map_page_positions(page_order);
+    for (page, page_position) in &page_positions {
+        if let Some(subsequent_pages) = page_order_rules.get(page) {
+            for subsequent_page in subsequent_pages {
+                if let Some(subsequent_position) = page_positions.get(subsequent_page) {
+                    if subsequent_position < page_position {
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+
+    true
+}
+
+fn correct_incorrect_order(page_order_rules: &HashMap<i32, HashSet<i32>>, page_order: &mut [i32]) {
+    page_order.sort_by(|page_1, page_2| {
+        if let Some(subsequent_pages) = page_order_rules.get(page_1) {
+            if subsequent_pages.contains(page_2) {
+                return Ordering::Less;
+            }
+        }
+        if let Some(subsequent_pages) = page_order_rules.get(page_2) {
+            if subsequent_pages.contains(page_1) {
+                return Ordering::Greater;
+            }
+        }
+
+        Ordering::Equal
+    })
+}
+
+fn get_middle_page_number(page_order: &[i32]) -> i32 {
+    let middle_page_index = (page_order.len() as f64 / 2.0).floor() as usize;
+    page_order[middle_page_index]
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = (PageOrderRules, Vec<Vec<i32>>);
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: include_str!("../inputs/examples/day_5.txt"),
+        part_one: Some("143"),
+        part_two: Some("123"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((page_order_rules, page_orders): &Self::Input) -> Option<Answer> {
+        Some(
+            page_orders
+                .iter()
+                .filter(|page_order| is_valid_page_order(page_order_rules, page_order))
+                .map(|correct_page_order| get_middle_page_number(correct_page_order))
+                .sum::<i32>()
+                .into(),
+        )
+    }
+
+    fn part_two((page_order_rules, page_orders): &Self::Input) -> Option<Answer> {
+        let mut sum_of_corrected_orders = 0;
+        for page_order in page_orders {
+            if !is_valid_page_order(page_order_rules, page_order) {
+                let mut incorrect_order = page_order.clone();
+                correct_incorrect_order(page_order_rules, &mut incorrect_order);
+                sum_of_corrected_orders += get_middle_page_number(&incorrect_order);
+            }
+        }
+
+        Some(sum_of_corrected_orders.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_page_positions() {
+        let pages = vec![1, 2, 3];
+        assert_eq!(
+            map_page_positions(&pages),
+            HashMap::from([(1, 0), (2, 1), (3, 2)])
+        );
+    }
+
+    #[test]
+    fn test_valid_page_order_check() {
+        let page_order_rules =
+            HashMap::from([(1, HashSet::from([2, 3])), (2, HashSet::from([3, 4, 5]))]);
+
+        assert!(is_valid_page_order(&page_order_rules, &[1, 2, 3]));
+        assert!(!is_valid_page_order(&page_order_rules, &[3, 2, 1]));
+    }
+
+    #[test]
+    fn test_get_middle_page_number() {
+        assert_eq!(get_middle_page_number(&[1, 2, 3]), 2);
+    }
+
+    #[test]
+    fn test_get_correct_order() {
+        let page_order_rules =
+            HashMap::from([(1, HashSet::from([2, 3])), (2, HashSet::from([3, 4, 5]))]);
+        let mut page_order = vec![3, 2, 1];
+        correct_incorrect_order(&page_order_rules, &mut page_order);
+        assert_eq!(page_order, vec![1, 2, 3])
+    }
+}
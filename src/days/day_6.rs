@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+
+use crate::grid::Grid;
+use crate::parse::{ParseError, Span};
+use crate::point::{Direction, Point};
+use crate::solution::{Answer, Example, Solution};
+
+#[derive(Clone)]
+pub struct Map {
+    blocked_fields: Grid<bool>,
+}
+
+impl Map {
+    fn is_field_blocked(&self, coordinates: Point) -> Option<bool> {
+        self.blocked_fields.get_point(coordinates).copied()
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Map, Point), ParseError> {
+    let fields = Grid::parse(input, "`.`, `#` or `^`", |field| {
+        matches!(field, '.' | '#' | '^').then_some(field)
+    })?;
+    let start_coordinates = fields
+        .position(|field| *field == '^')
+        .ok_or_else(|| Span::new(input).end().error("a guard `^`"))?;
+    let blocked_fields = fields.map(|field| *field == '#');
+
+    Ok((Map { blocked_fields }, start_coordinates))
+}
+
+fn find_path(start_coordinates: &Point, map: &Map) -> Option<Vec<Point>> {
+    let mut path = vec![*start_coordinates];
+    let mut path_directions = HashSet::from([(*start_coordinates, Direction::Up)]);
+    let mut current_direction = Direction::Up;
+    let mut current_coordinates = *start_coordinates;
+    let mut reached_map_border = false;
+
+    while !reached_map_border {
+        let next_coordinates = current_coordinates + current_direction.offset();
+        if path_directions.contains(&(next_coordinates, current_direction)) {
+            return None;
+        } else {
+            path_directions.insert((next_coordinates, current_direction));
+        }
+
+        if let Some(is_blocked) = map.is_field_blocked(next_coordinates) {
+            if is_blocked {
+                current_direction = current_direction.turn_right();
+            } else {
+                current_coordinates = next_coordinates;
+                path.push(current_coordinates);
+            }
+        } else {
+            reached_map_border = true;
+        }
+    }
+
+    Some(path)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = (Map, Point);
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: include_str!("../inputs/examples/day_6.txt"),
+        part_one: Some("41"),
+        part_two: Some("6"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((map, guard_start_coordinates): &Self::Input) -> Option<Answer> {
+        let path = find_path(guard_start_coordinates, map).unwrap();
+        let unique_coordinates: HashSet<Point> = HashSet::from_iter(path.iter().cloned());
+
+        Some(unique_coordinates.len().into())
+    }
+
+    fn part_two((map, guard_start_coordinates): &Self::Input) -> Option<Answer> {
+        let path = find_path(guard_start_coordinates, map).unwrap();
+        let mut possible_obstacles_for_loops = HashSet::new();
+        for obstacle in path[1..].iter() {
+            let mut additional_obstacle_map = map.clone();
+            additional_obstacle_map.blocked_fields[(obstacle.x as usize, obstacle.y as usize)] =
+                true;
+            if find_path(guard_start_coordinates, &additional_obstacle_map).is_none() {
+                possible_obstacles_for_loops.insert(obstacle);
+            }
+        }
+
+        Some(possible_obstacles_for_loops.len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_field_blocked() {
+        let map = Map {
+            blocked_fields: Grid::from_rows(vec![vec![true, false], vec![false, false]]).unwrap(),
+        };
+        assert_eq!(map.is_field_blocked(Point::new(0, 0)), Some(true));
+        assert_eq!(map.is_field_blocked(Point::new(1, 1)), Some(false));
+        assert_eq!(map.is_field_blocked(Point::new(-1, 0)), None);
+        assert_eq!(map.is_field_blocked(Point::new(0, 2)), None);
+    }
+
+    #[test]
+    fn test_find_path_without_loop() {
+        let map = Map {
+            blocked_fields: Grid::from_rows(vec![
+                vec![true, false, false],
+                vec![false, false, true],
+                vec![false, false, false],
+            ])
+            .unwrap(),
+        };
+        let path = find_path(&Point::new(0, 2), &map);
+        assert_eq!(
+            path,
+            Some(vec![
+                Point::new(0, 2),
+                Point::new(0, 1),
+                Point::new(1, 1),
+                Point::new(1, 2)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_find_path_with_loop() {
+        let map = Map {
+            blocked_fields: Grid::from_rows(vec![
+                vec![false, true, false, false],
+                vec![false, false, false, true],
+                vec![true, false, false, false],
+                vec![false, false, true, false],
+            ])
+            .unwrap(),
+        };
+        let path = find_path(&Point::new(1, 3), &map);
+        assert_eq!(path, None);
+    }
+}
@@ -0,0 +1,190 @@
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Example, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operator {
+    Plus,
+    Multiply,
+    Concatenate,
+}
+
+fn parse_input(input: &str) -> Result<Vec<(i64, Vec<i64>)>, ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|line| {
+            let (test_value, numbers) =
+                line.split_once(":", "a test value followed by `:` and numbers")?;
+            Ok((
+                test_value.parse("a test value")?,
+                numbers
+                    .split_whitespace()
+                    .map(|number| number.parse("a number"))
+                    .collect::<Result<_, _>>()?,
+            ))
+        })
+        .collect()
+}
+
+fn get_possible_operator_combinations(
+    required_operator_amount: usize,
+    allowed_operators: &[Operator],
+) -> Vec<Vec<Operator>> {
+    if required_operator_amount == 1 {
+        allowed_operators
+            .iter()
+            .map(|operator| vec![*operator])
+            .collect()
+    } else {
+        let combinations =
+            get_possible_operator_combinations(required_operator_amount - 1, allowed_operators);
+        let mut extended_combinations = Vec::new();
+
+        for allowed_operator in allowed_operators {
+            for combination in &combinations {
+                let mut extended_combination = vec![*allowed_operator];
+                extended_combination.extend(combination.clone());
+                extended_combinations.push(extended_combination);
+            }
+        }
+
+        extended_combinations
+    }
+}
+
+fn execute_calculation(numbers: &[i64], operators: &[Operator]) -> i64 {
+    let mut result = numbers[0];
+
+    for i in 1..numbers.len() {
+        result = match operators[i - 1] {
+            Operator::Plus => result + numbers[i],
+            Operator::Multiply => result * numbers[i],
+            Operator::Concatenate => format!("{result}{}", numbers[i]).parse().unwrap(),
+        };
+    }
+
+    result
+}
+
+fn find_valid_operator_combinations_for_equation(
+    test_value: i64,
+    numbers: &[i64],
+    allowed_operators: &[Operator],
+) -> Vec<Vec<Operator>> {
+    get_possible_operator_combinations(numbers.len() - 1, allowed_operators)
+        .iter()
+        .filter(|operator_combination| {
+            execute_calculation(numbers, operator_combination) == test_value
+        })
+        .cloned()
+        .collect()
+}
+
+fn get_sum_of_test_values_of_solvable_test_equations(
+    test_equations: &Vec<(i64, Vec<i64>)>,
+    allowed_operators: &[Operator],
+) -> i64 {
+    let mut sum_of_solvable_test_equations = 0;
+
+    for (test_value, numbers) in test_equations {
+        let valid_operator_combinations =
+            find_valid_operator_combinations_for_equation(*test_value, numbers, allowed_operators);
+        if !valid_operator_combinations.is_empty() {
+            sum_of_solvable_test_equations += test_value;
+        }
+    }
+
+    sum_of_solvable_test_equations
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<(i64, Vec<i64>)>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: include_str!("../inputs/examples/day_7.txt"),
+        part_one: Some("3749"),
+        part_two: Some("11387"),
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(test_equations: &Self::Input) -> Option<Answer> {
+        Some(
+            get_sum_of_test_values_of_solvable_test_equations(
+                test_equations,
+                &[Operator::Plus, Operator::Multiply],
+            )
+            .into(),
+        )
+    }
+
+    fn part_two(test_equations: &Self::Input) -> Option<Answer> {
+        Some(
+            get_sum_of_test_values_of_solvable_test_equations(
+                test_equations,
+                &[Operator::Plus, Operator::Multiply, Operator::Concatenate],
+            )
+            .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_possible_operator_combinations() {
+        assert_eq!(
+            get_possible_operator_combinations(1, &[Operator::Plus, Operator::Multiply]),
+            vec![vec![Operator::Plus], vec![Operator::Multiply]]
+        );
+
+        assert_eq!(
+            get_possible_operator_combinations(3, &[Operator::Plus, Operator::Multiply]),
+            vec![
+                vec![Operator::Plus, Operator::Plus, Operator::Plus],
+                vec![Operator::Plus, Operator::Plus, Operator::Multiply],
+                vec![Operator::Plus, Operator::Multiply, Operator::Plus],
+                vec![Operator::Plus, Operator::Multiply, Operator::Multiply],
+                vec![Operator::Multiply, Operator::Plus, Operator::Plus],
+                vec![Operator::Multiply, Operator::Plus, Operator::Multiply],
+                vec![Operator::Multiply, Operator::Multiply, Operator::Plus],
+                vec![Operator::Multiply, Operator::Multiply, Operator::Multiply],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_execute_calculation() {
+        assert_eq!(
+            execute_calculation(
+                &[1, 2, 3, 4],
+                &[Operator::Plus, Operator::Multiply, Operator::Concatenate]
+            ),
+            94
+        );
+    }
+
+    #[test]
+    fn test_find_valid_operator_combinations_for_equation() {
+        let valid_operator_combinations = find_valid_operator_combinations_for_equation(
+            3267,
+            &[81, 40, 27],
+            &[Operator::Plus, Operator::Multiply],
+        );
+        assert_eq!(
+            valid_operator_combinations,
+            vec![
+                vec![Operator::Plus, Operator::Multiply],
+                vec![Operator::Multiply, Operator::Plus]
+            ]
+        );
+    }
+}
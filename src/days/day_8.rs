@@ -0,0 +1,182 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+use crate::grid::Grid;
+use crate::number_theory::gcd;
+use crate::parse::ParseError;
+use crate::solution::{Answer, Example, Solution};
+
+type FrequencyMapping = HashMap<char, Vec<(i32, i32)>>;
+
+fn parse_input(input: &str) -> Result<(FrequencyMapping, i32, i32), ParseError> {
+    let antenna_map = Grid::parse(input, "`.` or an antenna frequency", |field_type| {
+        (field_type == '.' || field_type.is_ascii_alphanumeric()).then_some(field_type)
+    })?;
+    let mut antenna_locations = HashMap::new();
+
+    for ((x, y), field_type) in antenna_map.iter() {
+        if *field_type != '.' {
+            if let Entry::Vacant(e) = antenna_locations.entry(*field_type) {
+                e.insert(vec![(x as i32, y as i32)]);
+            } else {
+                antenna_locations
+                    .get_mut(field_type)
+                    .unwrap()
+                    .push((x as i32, y as i32));
+            }
+        }
+    }
+
+    Ok((
+        antenna_locations,
+        antenna_map.width() as i32 - 1,
+        antenna_map.height() as i32 - 1,
+    ))
+}
+
+fn find_possible_antinodes(
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+    max_x: i32,
+    max_y: i32,
+    step: i32,
+) -> Vec<(i32, i32)> {
+    let mut possible_antinodes = Vec::new();
+
+    let mut border_reached = false;
+    let mut i = step;
+    while !border_reached {
+        let x = x2 + i * (x2 - x1);
+        let y = y2 + i * (y2 - y1);
+        if x < 0 || y < 0 || x > max_x || y > max_y {
+            border_reached = true;
+        } else {
+            possible_antinodes.push((x, y));
+            i += step;
+        }
+    }
+
+    possible_antinodes
+}
+
+fn find_antinodes_for_frequency(
+    frequency_antenna_locations: &[(i32, i32)],
+    max_x: i32,
+    max_y: i32,
+    antinode_limit: bool,
+) -> HashSet<(i32, i32)> {
+    let mut antinode_locations = HashSet::new();
+
+    for (i, (x1, y1)) in frequency_antenna_locations.iter().enumerate() {
+        for (j, (x2, y2)) in frequency_antenna_locations.iter().enumerate() {
+            if i != j {
+                let possible_antinodes =
+                    find_possible_antinodes(*x1, *y1, *x2, *y2, max_x, max_y, 1);
+                if antinode_limit {
+                    if !possible_antinodes.is_empty() {
+                        antinode_locations.insert(possible_antinodes[0]);
+                    }
+                } else {
+                    // Every grid position in line with both antennas counts, including those
+                    // between multiples of their offset, so walk in steps of the reduced offset.
+                    let divisor = gcd((x2 - x1) as i64, (y2 - y1) as i64) as i32;
+                    let (x1, y1) = (x2 - (x2 - x1) / divisor, y2 - (y2 - y1) / divisor);
+                    antinode_locations
+                        .extend(find_possible_antinodes(x1, y1, *x2, *y2, max_x, max_y, 1));
+                    antinode_locations
+                        .extend(find_possible_antinodes(x1, y1, *x2, *y2, max_x, max_y, -1));
+                }
+            }
+        }
+    }
+
+    antinode_locations
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = (FrequencyMapping, i32, i32);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../inputs/examples/day_8.txt"),
+            part_one: Some("14"),
+            part_two: Some("34"),
+        },
+        Example {
+            name: "T antennas",
+            input: include_str!("../inputs/examples/day_8_t_antennas.txt"),
+            part_one: None,
+            part_two: Some("9"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((antenna_locations, max_x, max_y): &Self::Input) -> Option<Answer> {
+        let mut unique_antinode_locations = HashSet::new();
+        for frequency_antenna_locations in antenna_locations.values() {
+            unique_antinode_locations.extend(find_antinodes_for_frequency(
+                frequency_antenna_locations,
+                *max_x,
+                *max_y,
+                true,
+            ));
+        }
+
+        Some(unique_antinode_locations.len().into())
+    }
+
+    fn part_two((antenna_locations, max_x, max_y): &Self::Input) -> Option<Answer> {
+        let mut unique_antinode_locations = HashSet::new();
+        for frequency_antenna_locations in antenna_locations.values() {
+            unique_antinode_locations.extend(find_antinodes_for_frequency(
+                frequency_antenna_locations,
+                *max_x,
+                *max_y,
+                false,
+            ));
+        }
+
+        Some(unique_antinode_locations.len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_possible_antinodes() {
+        assert_eq!(
+            find_possible_antinodes(2, 2, 3, 3, 5, 5, -1),
+            vec![(2, 2), (1, 1), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_find_antinodes_for_frequency() {
+        let antenna_locations = vec![(4, 3), (8, 4), (5, 5)];
+        assert_eq!(
+            find_antinodes_for_frequency(&antenna_locations, 10, 10, true),
+            HashSet::from([(0, 2), (3, 1), (2, 6), (6, 7)])
+        );
+    }
+
+    #[test]
+    fn test_find_resonant_antinodes_between_antennas() {
+        // The offset (2, 4) reduces to (1, 2), so (1, 2) lies in line between the antennas.
+        assert_eq!(
+            find_antinodes_for_frequency(&[(0, 0), (2, 4)], 4, 4, false),
+            HashSet::from([(0, 0), (1, 2), (2, 4)])
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::solution::Day;
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_2;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+
+/// All solved days, ordered by day number.
+pub static DAYS: &[Day] = &[
+    Day::new::<day_1::Day1>(),
+    Day::new::<day_2::Day2>(),
+    Day::new::<day_3::Day3>(),
+    Day::new::<day_4::Day4>(),
+    Day::new::<day_5::Day5>(),
+    Day::new::<day_6::Day6>(),
+    Day::new::<day_7::Day7>(),
+    Day::new::<day_8::Day8>(),
+    Day::new::<day_9::Day9>(),
+    Day::new::<day_10::Day10>(),
+    Day::new::<day_11::Day11>(),
+    Day::new::<day_12::Day12>(),
+    Day::new::<day_13::Day13>(),
+    Day::new::<day_14::Day14>(),
+    Day::new::<day_15::Day15>(),
+    Day::new::<day_16::Day16>(),
+    Day::new::<day_17::Day17>(),
+    Day::new::<day_18::Day18>(),
+    Day::new::<day_19::Day19>(),
+    Day::new::<day_20::Day20>(),
+    Day::new::<day_21::Day21>(),
+    Day::new::<day_22::Day22>(),
+    Day::new::<day_23::Day23>(),
+    Day::new::<day_24::Day24>(),
+    Day::new::<day_25::Day25>(),
+];
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+
+    #[test]
+    fn test_days_are_ordered() {
+        assert!(DAYS.windows(2).all(|days| days[0].number < days[1].number));
+    }
+
+    #[test]
+    fn test_examples() {
+        for day in DAYS {
+            assert!(
+                !day.examples.is_empty(),
+                "day {} has no examples",
+                day.number
+            );
+            for example in day.examples {
+                let (part_one, part_two) =
+                    day.solve_example(example.input).unwrap_or_else(|error| {
+                        panic!("example `{}` does not parse: {error}", example.name)
+                    });
+                for (part, answer) in [(Part::One, part_one), (Part::Two, part_two)] {
+                    if let Some(expected) = example.expected(part) {
+                        assert_eq!(
+                            answer.map(|answer| answer.to_string()).as_deref(),
+                            Some(expected),
+                            "day {}, example `{}`, part {part}",
+                            day.number,
+                            example.name
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::client::{Client, ClientError};
+use crate::config::Config;
+
+pub const YEAR: u16 = 2024;
+
+/// 2024-12-01 05:00:00 UTC, i.e. midnight US Eastern time, when day 1 unlocked.
+const FIRST_UNLOCK_SECONDS: u64 = 1_733_029_200;
+
+/// When the puzzle of a day (1 to 25) becomes available.
+pub fn unlock_time(day: u8) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(FIRST_UNLOCK_SECONDS + (day as u64 - 1) * 24 * 60 * 60)
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    InvalidDay(u8),
+    Locked { day: u8, remaining: Duration },
+    MissingSession,
+    Request(ClientError),
+    Unwritable { path: PathBuf, error: io::Error },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDay(day) => write!(f, "there is no day {day} in Advent of Code"),
+            Self::Locked { day, remaining } => {
+                let minutes = remaining.as_secs().div_ceil(60);
+                write!(
+                    f,
+                    "day {day} is still locked, it unlocks in {}h {:02}m",
+                    minutes / 60,
+                    minutes % 60
+                )
+            }
+            Self::MissingSession => write!(
+                f,
+                "no session token, set `AOC_SESSION` or `session` in the config file"
+            ),
+            Self::Request(ClientError::Status {
+                status: 400 | 401 | 403,
+                ..
+            }) => write!(
+                f,
+                "the session token was rejected, it may have expired (log in again and update it)"
+            ),
+            Self::Request(error) => write!(f, "could not download puzzle input: {error}"),
+            Self::Unwritable { path, error } => {
+                write!(f, "could not write {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl From<ClientError> for FetchError {
+    fn from(error: ClientError) -> Self {
+        Self::Request(error)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Makes sure the input of `day` is stored at `path`, downloading it unless a non-empty copy is
+/// already there. `force` downloads it even then.
+pub fn fetch_input(
+    config: &Config,
+    day: u8,
+    path: &Path,
+    force: bool,
+    now: SystemTime,
+) -> Result<Fetched, FetchError> {
+    if !(1..=25).contains(&day) {
+        return Err(FetchError::InvalidDay(day));
+    }
+    let is_cached = fs::read_to_string(path).is_ok_and(|input| !input.trim().is_empty());
+    if is_cached && !force {
+        return Ok(Fetched::Cached);
+    }
+    if let Ok(remaining) = unlock_time(day).duration_since(now) {
+        return Err(FetchError::Locked { day, remaining });
+    }
+
+    let session = config.session.as_ref().ok_or(FetchError::MissingSession)?;
+    let client = Client::new(&config.base_url, session);
+    let input = client.get(&format!("/{YEAR}/day/{day}/input"))?;
+
+    let unwritable = |error| FetchError::Unwritable {
+        path: path.to_path_buf(),
+        error,
+    };
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory).map_err(unwritable)?;
+    }
+    fs::write(path, input).map_err(unwritable)?;
+
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::USER_AGENT;
+    use crate::stub_server::StubServer;
+
+    fn temporary_path(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("aoc2024-fetch-{}", std::process::id()));
+        let path = directory.join(name).join("input.txt");
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    fn config(base_url: &str) -> Config {
+        Config {
+            session: Some("secret".to_string()),
+            base_url: base_url.to_string(),
+        }
+    }
+
+    fn december(day: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(FIRST_UNLOCK_SECONDS + (day - 1) * 24 * 60 * 60)
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(
+            unlock_time(1).duration_since(UNIX_EPOCH).unwrap(),
+            Duration::from_secs(1_733_029_200)
+        );
+        assert_eq!(
+            unlock_time(25).duration_since(unlock_time(1)).unwrap(),
+            Duration::from_secs(24 * 24 * 60 * 60)
+        );
+    }
+
+    #[test]
+    fn test_fetch_downloads_and_caches() {
+        let server = StubServer::serve(vec![(200, "3   4\n4   3\n")]);
+        let config = config(&server.base_url);
+        let path = temporary_path("download");
+
+        assert_eq!(
+            fetch_input(&config, 1, &path, false, december(30)).unwrap(),
+            Fetched::Downloaded
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2024/day/1/input");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+        assert_eq!(requests[0].header("User-Agent"), Some(USER_AGENT));
+
+        // The stub server is gone, so this only works from the cache.
+        assert_eq!(
+            fetch_input(&config, 1, &path, false, december(30)).unwrap(),
+            Fetched::Cached
+        );
+    }
+
+    #[test]
+    fn test_fetch_refuses_locked_days() {
+        let path = temporary_path("locked");
+        let error = fetch_input(
+            &config("http://127.0.0.1:9"),
+            14,
+            &path,
+            false,
+            december(14) - Duration::from_secs(90 * 60),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 14 is still locked, it unlocks in 1h 30m"
+        );
+        assert!(!path.exists());
+
+        assert!(matches!(
+            fetch_input(
+                &config("http://127.0.0.1:9"),
+                26,
+                &path,
+                false,
+                december(30)
+            ),
+            Err(FetchError::InvalidDay(26))
+        ));
+    }
+
+    #[test]
+    fn test_fetch_without_session() {
+        let config = Config {
+            session: None,
+            ..Config::default()
+        };
+        assert!(matches!(
+            fetch_input(
+                &config,
+                3,
+                &temporary_path("no-session"),
+                false,
+                december(30)
+            ),
+            Err(FetchError::MissingSession)
+        ));
+    }
+
+    #[test]
+    fn test_fetch_with_rejected_session() {
+        let server = StubServer::serve(vec![(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        )]);
+        let path = temporary_path("rejected");
+        let error =
+            fetch_input(&config(&server.base_url), 2, &path, true, december(30)).unwrap_err();
+        assert!(error.to_string().contains("session token was rejected"));
+        assert!(!path.exists());
+        server.requests();
+    }
+
+    #[test]
+    fn test_fetch_with_server_error() {
+        let server = StubServer::serve(vec![(500, "Internal Server Error\n")]);
+        let path = temporary_path("server-error");
+        let error =
+            fetch_input(&config(&server.base_url), 2, &path, true, december(30)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "could not download puzzle input: server responded with status 500: Internal Server Error"
+        );
+        assert!(!path.exists());
+        server.requests();
+    }
+}
@@ -0,0 +1,241 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::number_theory::gcd_i128;
+
+/// An exact rational number. It is always kept in lowest terms with a positive denominator, so
+/// equal values compare and hash equally.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fraction {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Fraction {
+    pub const ZERO: Fraction = Fraction::integer(0);
+    pub const ONE: Fraction = Fraction::integer(1);
+
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert!(
+            denominator != 0,
+            "the denominator of a fraction must not be zero"
+        );
+        let divisor = gcd_i128(numerator, denominator) * denominator.signum();
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub const fn integer(value: i128) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    pub fn numerator(self) -> i128 {
+        self.numerator
+    }
+
+    /// The denominator, which is always positive.
+    pub fn denominator(self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.denominator == 1
+    }
+
+    /// The value as an integer, unless it has a fractional part.
+    pub fn to_integer(self) -> Option<i128> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    /// The reciprocal `1 / self`. Panics for zero.
+    pub fn recip(self) -> Self {
+        Self::new(self.denominator, self.numerator)
+    }
+}
+
+impl Default for Fraction {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<i128> for Fraction {
+    fn from(value: i128) -> Self {
+        Self::integer(value)
+    }
+}
+
+impl From<i64> for Fraction {
+    fn from(value: i64) -> Self {
+        Self::integer(value.into())
+    }
+}
+
+impl From<i32> for Fraction {
+    fn from(value: i32) -> Self {
+        Self::integer(value.into())
+    }
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl Ord for Fraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Both denominators are positive, so cross-multiplying keeps the order.
+        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+    }
+}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for Fraction {
+    type Output = Fraction;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Add for Fraction {
+    type Output = Fraction;
+
+    fn add(self, other: Fraction) -> Self::Output {
+        // Scaling to the least common denominator keeps the intermediate values small.
+        let divisor = gcd_i128(self.denominator, other.denominator);
+        let (self_scale, other_scale) = (other.denominator / divisor, self.denominator / divisor);
+        Self::new(
+            self.numerator * self_scale + other.numerator * other_scale,
+            self.denominator * self_scale,
+        )
+    }
+}
+
+impl Sub for Fraction {
+    type Output = Fraction;
+
+    fn sub(self, other: Fraction) -> Self::Output {
+        self + -other
+    }
+}
+
+impl Mul for Fraction {
+    type Output = Fraction;
+
+    fn mul(self, other: Fraction) -> Self::Output {
+        // Cancelling crosswise first keeps the intermediate values small.
+        let first = gcd_i128(self.numerator, other.denominator);
+        let second = gcd_i128(other.numerator, self.denominator);
+        Self::new(
+            (self.numerator / first) * (other.numerator / second),
+            (self.denominator / second) * (other.denominator / first),
+        )
+    }
+}
+
+impl Div for Fraction {
+    type Output = Fraction;
+
+    /// Panics when dividing by zero.
+    fn div(self, other: Fraction) -> Self::Output {
+        assert!(!other.is_zero(), "cannot divide a fraction by zero");
+        let numerators = gcd_i128(self.numerator, other.numerator);
+        let denominators = gcd_i128(self.denominator, other.denominator);
+        Self::new(
+            (self.numerator / numerators) * (other.denominator / denominators),
+            (self.denominator / denominators) * (other.numerator / numerators),
+        )
+    }
+}
+
+impl AddAssign for Fraction {
+    fn add_assign(&mut self, other: Fraction) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Fraction {
+    fn sub_assign(&mut self, other: Fraction) {
+        *self = *self - other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_reduces() {
+        let fraction = Fraction::new(6, -8);
+        assert_eq!((fraction.numerator(), fraction.denominator()), (-3, 4));
+        assert_eq!(Fraction::new(0, -5), Fraction::ZERO);
+        assert_eq!(Fraction::new(10, 5).to_integer(), Some(2));
+        assert_eq!(Fraction::new(10, 4).to_integer(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "must not be zero")]
+    fn test_zero_denominator() {
+        Fraction::new(1, 0);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let half = Fraction::new(1, 2);
+        let third = Fraction::new(1, 3);
+        assert_eq!(half + third, Fraction::new(5, 6));
+        assert_eq!(half - third, Fraction::new(1, 6));
+        assert_eq!(half * third, Fraction::new(1, 6));
+        assert_eq!(half / third, Fraction::new(3, 2));
+        assert_eq!(-half, Fraction::new(-1, 2));
+        assert_eq!(Fraction::new(-2, 3).recip(), Fraction::new(-3, 2));
+        assert_eq!(half * Fraction::ZERO, Fraction::ZERO);
+        assert_eq!(Fraction::ZERO / half, Fraction::ZERO);
+
+        let mut sum = Fraction::ZERO;
+        for _ in 0..6 {
+            sum += Fraction::new(1, 6);
+        }
+        assert_eq!(sum, Fraction::ONE);
+    }
+
+    #[test]
+    fn test_ordering_and_display() {
+        assert!(Fraction::new(-1, 2) < Fraction::new(-1, 3));
+        assert!(Fraction::new(7, 3) > Fraction::from(2));
+        assert_eq!(Fraction::new(-4, 6).to_string(), "-2/3");
+        assert_eq!(Fraction::from(5_i64).to_string(), "5");
+    }
+
+    #[test]
+    fn test_large_values_stay_exact() {
+        let big = Fraction::new(10_000_000_000_000, 3);
+        let product = big * Fraction::new(3, 10_000_000_000_000);
+        assert_eq!(product, Fraction::ONE);
+        assert_eq!((big + big).to_string(), "20000000000000/3");
+    }
+}
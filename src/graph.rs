@@ -0,0 +1,198 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// An undirected graph without parallel edges or self-loops, stored as a set of neighbours per
+/// node.
+#[derive(Clone, Debug)]
+pub struct Graph<N> {
+    adjacency: HashMap<N, HashSet<N>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self {
+            adjacency: HashMap::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, node: N) {
+        self.adjacency.entry(node).or_default();
+    }
+
+    /// Adds the edge between `a` and `b`, and both nodes if they are new. An edge from a node to
+    /// itself only adds the node.
+    pub fn add_edge(&mut self, a: N, b: N) {
+        if a == b {
+            self.add_node(a);
+            return;
+        }
+        self.adjacency
+            .entry(a.clone())
+            .or_default()
+            .insert(b.clone());
+        self.adjacency.entry(b).or_default().insert(a);
+    }
+
+    pub fn contains_edge(&self, a: &N, b: &N) -> bool {
+        self.adjacency
+            .get(a)
+            .is_some_and(|neighbours| neighbours.contains(b))
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.adjacency.keys()
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// The neighbours of `node`, or none if it is not in the graph.
+    pub fn neighbours(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.adjacency.get(node).into_iter().flatten()
+    }
+
+    pub fn degree(&self, node: &N) -> usize {
+        self.adjacency.get(node).map_or(0, HashSet::len)
+    }
+
+    /// Every edge once, as a pair of its two nodes.
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N)> + '_
+    where
+        N: Ord,
+    {
+        self.adjacency.iter().flat_map(|(a, neighbours)| {
+            neighbours
+                .iter()
+                .filter(move |b| a < *b)
+                .map(move |b| (a, b))
+        })
+    }
+
+    /// A largest set of nodes that are all connected to each other. Found with the Bron–Kerbosch
+    /// algorithm, pivoting on the node that leaves the fewest candidates to branch on.
+    pub fn maximum_clique(&self) -> Vec<N> {
+        let mut best = Vec::new();
+        let candidates = self.adjacency.keys().cloned().collect();
+        self.extend_clique(&mut Vec::new(), candidates, HashSet::new(), &mut best);
+        best
+    }
+
+    fn extend_clique(
+        &self,
+        clique: &mut Vec<N>,
+        mut candidates: HashSet<N>,
+        mut excluded: HashSet<N>,
+        best: &mut Vec<N>,
+    ) {
+        if candidates.is_empty() && excluded.is_empty() {
+            if clique.len() > best.len() {
+                *best = clique.clone();
+            }
+            return;
+        }
+        if clique.len() + candidates.len() <= best.len() {
+            return;
+        }
+
+        let pivot_neighbours = candidates
+            .iter()
+            .chain(&excluded)
+            .map(|pivot| &self.adjacency[pivot])
+            .max_by_key(|neighbours| neighbours.intersection(&candidates).count())
+            .cloned()
+            .unwrap_or_default();
+        let branches = candidates
+            .difference(&pivot_neighbours)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for node in branches {
+            let neighbours = &self.adjacency[&node];
+            clique.push(node.clone());
+            self.extend_clique(
+                clique,
+                candidates.intersection(neighbours).cloned().collect(),
+                excluded.intersection(neighbours).cloned().collect(),
+                best,
+            );
+            clique.pop();
+            candidates.remove(&node);
+            excluded.insert(node);
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> FromIterator<(N, N)> for Graph<N> {
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(edges: I) -> Self {
+        let mut graph = Self::new();
+        for (a, b) in edges {
+            graph.add_edge(a, b);
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted<'a>(nodes: impl Iterator<Item = &'a u32>) -> Vec<u32> {
+        let mut nodes = nodes.copied().collect::<Vec<_>>();
+        nodes.sort();
+        nodes
+    }
+
+    #[test]
+    fn test_adjacency() {
+        let mut graph = Graph::from_iter([(1, 2), (2, 3), (3, 1), (2, 1)]);
+        graph.add_node(4);
+        graph.add_edge(5, 5);
+        assert_eq!(graph.node_count(), 5);
+        assert!(!graph.contains_edge(&5, &5));
+        assert_eq!(graph.degree(&5), 0);
+        assert!(graph.contains_edge(&1, &2));
+        assert!(graph.contains_edge(&2, &1));
+        assert!(!graph.contains_edge(&1, &4));
+        assert_eq!(sorted(graph.neighbours(&2)), vec![1, 3]);
+        assert_eq!(sorted(graph.neighbours(&5)), vec![]);
+        assert_eq!(graph.degree(&4), 0);
+
+        let mut edges = graph.edges().map(|(a, b)| (*a, *b)).collect::<Vec<_>>();
+        edges.sort();
+        assert_eq!(edges, vec![(1, 2), (1, 3), (2, 3)]);
+    }
+
+    #[test]
+    fn test_maximum_clique() {
+        // A square with one diagonal, a separate triangle, and a 4-clique attached by one edge.
+        let graph = Graph::from_iter([
+            (1, 2),
+            (2, 3),
+            (3, 4),
+            (4, 1),
+            (1, 3),
+            (5, 6),
+            (6, 7),
+            (7, 5),
+            (8, 9),
+            (8, 10),
+            (8, 11),
+            (9, 10),
+            (9, 11),
+            (10, 11),
+            (11, 4),
+        ]);
+        let mut clique = graph.maximum_clique();
+        clique.sort();
+        assert_eq!(clique, vec![8, 9, 10, 11]);
+
+        assert_eq!(Graph::<u32>::new().maximum_clique(), vec![]);
+    }
+}
@@ -0,0 +1,418 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{ParseError, Span};
+use crate::point::{Direction, Point};
+
+/// Offsets of the 4-neighbourhood, clockwise starting north.
+pub const ORTHOGONAL_OFFSETS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the 8-neighbourhood, clockwise starting north.
+pub const ALL_OFFSETS: [(i32, i32); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    Ragged {
+        row: usize,
+        expected_width: usize,
+        width: usize,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "grid has no cells"),
+            Self::Ragged {
+                row,
+                expected_width,
+                width,
+            } => write!(
+                f,
+                "grid row {row} has {width} cells, but the rows before have {expected_width}"
+            ),
+        }
+    }
+}
+
+/// A rectangular 2D grid stored row by row. Positions are `(x, y)` with `(0, 0)` top-left.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        if width == 0 {
+            return Err(GridError::Empty);
+        }
+        if let Some((row, ragged_row)) = rows.iter().enumerate().find(|(_, r)| r.len() != width) {
+            return Err(GridError::Ragged {
+                row,
+                expected_width: width,
+                width: ragged_row.len(),
+            });
+        }
+
+        Ok(Self {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one cell per character, one row per line. `expected` describes a valid cell.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in Span::new(input).lines() {
+            for (c, cell) in line.chars() {
+                cells.push(parse_cell(c).ok_or_else(|| cell.error(expected))?);
+            }
+            let row_width = line.text().chars().count();
+            match width {
+                None if row_width > 0 => width = Some(row_width),
+                Some(width) if width == row_width => {}
+                Some(width) => return Err(line.error(&format!("a row of {width} cells"))),
+                None => return Err(line.error("a row of cells")),
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+            None => Err(Span::new(input).error("a grid")),
+        }
+    }
+
+    pub fn map<U>(&self, map_cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(map_cell).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Converts signed coordinates into a position if they lie on the grid.
+    pub fn checked_position(&self, (x, y): (i32, i32)) -> Option<(usize, usize)> {
+        if x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    pub fn get_signed(&self, coordinates: (i32, i32)) -> Option<&T> {
+        self.checked_position(coordinates)
+            .and_then(|position| self.get(position))
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get_signed(point.into())
+    }
+
+    pub fn get_point_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.checked_position(point.into())
+            .and_then(|position| self.get_mut(position))
+    }
+
+    pub fn neighbour(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (i32, i32),
+    ) -> Option<(usize, usize)> {
+        self.checked_position((x as i32 + dx, y as i32 + dy))
+    }
+
+    /// The position one step in `direction`, if it lies on the grid.
+    pub fn step(&self, position: (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        self.neighbour(position, direction.offset().into())
+    }
+
+    pub fn neighbours_4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL_OFFSETS
+            .iter()
+            .flat_map(move |offset| self.neighbour(position, *offset))
+    }
+
+    pub fn neighbours_8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL_OFFSETS
+            .iter()
+            .flat_map(move |offset| self.neighbour(position, *offset))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| (index % width, index / width))
+    }
+
+    /// All cells with their positions in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position in row-major order whose cell matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| Point::from(position))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("position {position:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {position:?} is outside of the grid"))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get_point(point)
+            .unwrap_or_else(|| panic!("point {point} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_point_mut(point)
+            .unwrap_or_else(|| panic!("point {point} is outside of the grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters() -> Grid<char> {
+        Grid::parse("abc\ndef\n", "a letter", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = letters();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(0, 0)], 'a');
+        assert_eq!(grid[(2, 1)], 'f');
+    }
+
+    #[test]
+    fn test_parse_invalid_input() {
+        assert_eq!(
+            Grid::parse("abc\nde\nfgh", "a letter", Some)
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: expected a row of 3 cells, found `de`"
+        );
+        assert_eq!(
+            Grid::parse("12\n3x", "a digit", |c| c.to_digit(10))
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 2: expected a digit, found `x`"
+        );
+        assert_eq!(
+            Grid::parse("", "a letter", Some).unwrap_err().to_string(),
+            "line 1, column 1: expected a grid, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_from_rows() {
+        assert_eq!(
+            Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).map(|grid| grid[(1, 1)]),
+            Ok(4)
+        );
+        assert_eq!(
+            Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5], vec![6, 7, 8]]),
+            Err(GridError::Ragged {
+                row: 1,
+                expected_width: 3,
+                width: 2
+            })
+        );
+        assert_eq!(Grid::<u8>::from_rows(vec![]), Err(GridError::Empty));
+        assert_eq!(
+            Grid::filled(2, 2, 0),
+            Grid::from_rows(vec![vec![0; 2]; 2]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_checked_indexing() {
+        let grid = letters();
+        assert_eq!(grid.get((1, 1)), Some(&'e'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.get_signed((-1, 0)), None);
+        assert_eq!(grid.get_signed((0, -1)), None);
+        assert_eq!(grid.get_signed((2, 0)), Some(&'c'));
+        assert_eq!(grid.checked_position((2, 1)), Some((2, 1)));
+        assert_eq!(grid.checked_position((3, 1)), None);
+    }
+
+    #[test]
+    fn test_point_indexing() {
+        let mut grid = letters();
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.get_point(Point::new(-1, 0)), None);
+        assert_eq!(grid.get_point(Point::new(0, 2)), None);
+        grid[Point::new(0, 1)] = 'x';
+        assert_eq!(
+            grid.position(|letter| *letter == 'x'),
+            Some(Point::new(0, 1))
+        );
+        assert_eq!(grid.position(|letter| *letter == 'z'), None);
+        assert_eq!(grid.step((0, 0), Direction::Right), Some((1, 0)));
+        assert_eq!(grid.step((0, 0), Direction::Up), None);
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = letters().map(|letter| *letter == 'e');
+        assert!(grid[(1, 1)]);
+        assert_eq!(grid.iter().filter(|(_, is_e)| **is_e).count(), 1);
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut grid = letters();
+        grid[(1, 0)] = 'x';
+        assert_eq!(grid.get((1, 0)), Some(&'x'));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = letters();
+        assert_eq!(
+            grid.rows().collect::<Vec<&[char]>>(),
+            vec![&['a', 'b', 'c'][..], &['d', 'e', 'f'][..]]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect())
+                .collect::<Vec<String>>(),
+            vec!["ad", "be", "cf"]
+        );
+    }
+
+    #[test]
+    fn test_iter() {
+        let grid = letters();
+        assert_eq!(
+            grid.iter().take(4).collect::<Vec<_>>(),
+            vec![
+                ((0, 0), &'a'),
+                ((1, 0), &'b'),
+                ((2, 0), &'c'),
+                ((0, 1), &'d')
+            ]
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = letters();
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours_4((1, 1)).collect::<Vec<_>>(),
+            vec![(1, 0), (2, 1), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours_8((1, 1)).collect::<Vec<_>>(),
+            vec![(1, 0), (2, 0), (2, 1), (0, 1), (0, 0)]
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Where the puzzle input of a day is read from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn from_argument(argument: &str) -> Self {
+        if argument == "-" {
+            Self::Stdin
+        } else {
+            Self::File(PathBuf::from(argument))
+        }
+    }
+
+    /// The bundled input file the day falls back to, if it is read from the default source but
+    /// has no fetched input.
+    pub fn bundled_fallback(&self, day: u8) -> Option<PathBuf> {
+        (*self == Self::Default && !has_fetched_input(day)).then(|| bundled_input_path(day))
+    }
+
+    fn describe(&self, day: u8) -> String {
+        match self {
+            Self::Default => default_input_path(day).display().to_string(),
+            Self::File(path) => path.display().to_string(),
+            Self::Stdin => "stdin".to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The day has neither a fetched nor a bundled input.
+    NotFetched {
+        day: u8,
+    },
+    Unreadable {
+        source: String,
+        error: io::Error,
+    },
+    Empty {
+        source: String,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFetched { day } => write!(
+                f,
+                "day {day} has no bundled input, fetch the input first with `aoc fetch {day}`"
+            ),
+            Self::Unreadable { source, error } => {
+                write!(f, "could not read puzzle input from {source}: {error}")
+            }
+            Self::Empty { source } => write!(f, "puzzle input from {source} is empty"),
+        }
+    }
+}
+
+fn inputs_directory() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("inputs")
+}
+
+/// Where `fetch` stores the real puzzle input of a day. The directory is ignored by git, since
+/// puzzle inputs are personal and must not be published.
+pub fn fetched_input_path(day: u8) -> PathBuf {
+    inputs_directory()
+        .join("real")
+        .join(format!("day_{day}.txt"))
+}
+
+pub fn has_fetched_input(day: u8) -> bool {
+    fetched_input_path(day).is_file()
+}
+
+/// The input shipped with the repository. Days whose puzzle uses other parameters than its
+/// examples, like a larger area, have none, since an example would give meaningless answers.
+pub fn bundled_input_path(day: u8) -> PathBuf {
+    inputs_directory().join(format!("data_day_{day}.txt"))
+}
+
+/// The fetched input of a day if there is one, otherwise the bundled one.
+pub fn default_input_path(day: u8) -> PathBuf {
+    if has_fetched_input(day) {
+        fetched_input_path(day)
+    } else {
+        bundled_input_path(day)
+    }
+}
+
+pub fn load_input(day: u8, input_source: &InputSource) -> Result<String, InputError> {
+    let input = match input_source {
+        InputSource::Default => fs::read_to_string(default_input_path(day)),
+        InputSource::File(path) => fs::read_to_string(path),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input).map(|_| input)
+        }
+    }
+    .map_err(|error| match input_source.bundled_fallback(day) {
+        Some(_) if error.kind() == io::ErrorKind::NotFound => InputError::NotFetched { day },
+        _ => InputError::Unreadable {
+            source: input_source.describe(day),
+            error,
+        },
+    })?;
+
+    if input.trim().is_empty() {
+        Err(InputError::Empty {
+            source: input_source.describe(day),
+        })
+    } else {
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_source_from_argument() {
+        assert_eq!(InputSource::from_argument("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_argument("input.txt"),
+            InputSource::File(PathBuf::from("input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_fetched_input_path() {
+        let path = fetched_input_path(7);
+        assert!(path.ends_with("src/inputs/real/day_7.txt"));
+        assert_ne!(path, bundled_input_path(7));
+        assert!(bundled_input_path(7).ends_with("src/inputs/data_day_7.txt"));
+    }
+
+    #[test]
+    fn test_bundled_fallback() {
+        let expected = (!has_fetched_input(1)).then(|| bundled_input_path(1));
+        assert_eq!(InputSource::Default.bundled_fallback(1), expected);
+        assert_eq!(InputSource::Stdin.bundled_fallback(1), None);
+    }
+
+    #[test]
+    fn test_load_input_without_bundled_input() {
+        if !has_fetched_input(14) {
+            let error = load_input(14, &InputSource::Default).unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                "day 14 has no bundled input, fetch the input first with `aoc fetch 14`"
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_default_input() {
+        assert!(load_input(1, &InputSource::Default).is_ok());
+    }
+
+    #[test]
+    fn test_load_missing_input() {
+        let input_source = InputSource::File(PathBuf::from("does/not/exist.txt"));
+        let error = load_input(1, &input_source).unwrap_err();
+        assert!(matches!(error, InputError::Unreadable { .. }));
+        assert!(error
+            .to_string()
+            .starts_with("could not read puzzle input from does/not/exist.txt"));
+    }
+
+    #[test]
+    fn test_load_empty_input() {
+        let path = std::env::temp_dir().join("aoc2024_test_load_empty_input.txt");
+        fs::write(&path, "\n").unwrap();
+        let error = load_input(1, &InputSource::File(path.clone())).unwrap_err();
+        fs::remove_file(path).unwrap();
+        assert!(matches!(error, InputError::Empty { .. }));
+    }
+}
@@ -0,0 +1,25 @@
+pub mod answers;
+pub mod cli;
+pub mod client;
+pub mod config;
+pub mod days;
+pub mod fetch;
+pub mod fraction;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod matrix;
+pub mod number_theory;
+pub mod parse;
+pub mod point;
+pub mod report;
+pub mod runner;
+pub mod search;
+pub mod solution;
+pub mod submit;
+pub mod table;
+pub mod timing;
+pub mod verify;
+
+#[cfg(test)]
+mod stub_server;
@@ -0,0 +1,268 @@
+use std::ops::Index;
+
+use crate::fraction::Fraction;
+
+/// A matrix of exact fractions, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matrix {
+    columns: usize,
+    rows: Vec<Vec<Fraction>>,
+}
+
+/// The solutions of a linear system `A·x = b`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Solutions {
+    None,
+    Unique(Vec<Fraction>),
+    /// `particular` plus any combination of the `null_space` basis vectors.
+    Infinite {
+        particular: Vec<Fraction>,
+        null_space: Vec<Vec<Fraction>>,
+    },
+}
+
+impl Matrix {
+    pub fn zero(rows: usize, columns: usize) -> Self {
+        Self {
+            columns,
+            rows: vec![vec![Fraction::ZERO; columns]; rows],
+        }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        let mut matrix = Self::zero(size, size);
+        for (index, row) in matrix.rows.iter_mut().enumerate() {
+            row[index] = Fraction::ONE;
+        }
+        matrix
+    }
+
+    /// Panics if the rows have different lengths.
+    pub fn from_rows(rows: Vec<Vec<Fraction>>) -> Self {
+        let columns = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == columns),
+            "all rows of a matrix must have the same length"
+        );
+        Self { columns, rows }
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn column_count(&self) -> usize {
+        self.columns
+    }
+
+    pub fn row(&self, row: usize) -> &[Fraction] {
+        &self.rows[row]
+    }
+
+    /// The product `A·vector`. Panics if the vector's length does not match the columns.
+    pub fn mul_vector(&self, vector: &[Fraction]) -> Vec<Fraction> {
+        assert_eq!(
+            vector.len(),
+            self.columns,
+            "vector length must match the columns"
+        );
+        self.rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(vector)
+                    .fold(Fraction::ZERO, |sum, (&entry, &value)| sum + entry * value)
+            })
+            .collect()
+    }
+
+    /// Gauss–Jordan elimination: the reduced row echelon form, together with the column of the
+    /// leading one in each of its non-zero rows.
+    pub fn reduced_row_echelon(&self) -> (Matrix, Vec<usize>) {
+        let mut rows = self.rows.clone();
+        let mut pivots = Vec::new();
+
+        for column in 0..self.columns {
+            let pivot_row = pivots.len();
+            if pivot_row == rows.len() {
+                break;
+            }
+            let Some(found) = (pivot_row..rows.len()).find(|&row| !rows[row][column].is_zero())
+            else {
+                continue;
+            };
+            rows.swap(pivot_row, found);
+
+            let pivot = rows[pivot_row][column];
+            for value in &mut rows[pivot_row] {
+                *value = *value / pivot;
+            }
+            let pivot_values = rows[pivot_row].clone();
+            for (index, row) in rows.iter_mut().enumerate() {
+                let factor = row[column];
+                if index != pivot_row && !factor.is_zero() {
+                    for (value, &pivot_value) in row.iter_mut().zip(&pivot_values).skip(column) {
+                        *value -= factor * pivot_value;
+                    }
+                }
+            }
+            pivots.push(column);
+        }
+
+        (
+            Matrix {
+                columns: self.columns,
+                rows,
+            },
+            pivots,
+        )
+    }
+
+    pub fn rank(&self) -> usize {
+        self.reduced_row_echelon().1.len()
+    }
+
+    /// A basis of the vectors `x` with `A·x = 0`, with one vector per column without a pivot.
+    pub fn null_space(&self) -> Vec<Vec<Fraction>> {
+        let (reduced, pivots) = self.reduced_row_echelon();
+        null_space_basis(&reduced, &pivots, self.columns)
+    }
+
+    /// Solves `A·x = rhs` by eliminating the augmented matrix `[A | rhs]`. Panics if `rhs` does not
+    /// have one value per row.
+    pub fn solve(&self, rhs: &[Fraction]) -> Solutions {
+        assert_eq!(
+            rhs.len(),
+            self.rows.len(),
+            "rhs must have one value per row"
+        );
+        let augmented = Matrix {
+            columns: self.columns + 1,
+            rows: self
+                .rows
+                .iter()
+                .zip(rhs)
+                .map(|(row, &value)| row.iter().copied().chain([value]).collect())
+                .collect(),
+        };
+        let (reduced, pivots) = augmented.reduced_row_echelon();
+
+        // A pivot in the last column is the contradiction `0 = 1`.
+        if pivots.last() == Some(&self.columns) {
+            return Solutions::None;
+        }
+        let mut particular = vec![Fraction::ZERO; self.columns];
+        for (row, &column) in pivots.iter().enumerate() {
+            particular[column] = reduced[(row, self.columns)];
+        }
+
+        if pivots.len() == self.columns {
+            Solutions::Unique(particular)
+        } else {
+            Solutions::Infinite {
+                particular,
+                null_space: null_space_basis(&reduced, &pivots, self.columns),
+            }
+        }
+    }
+}
+
+/// Sets each free column to one in turn and solves the pivot columns for it.
+fn null_space_basis(reduced: &Matrix, pivots: &[usize], columns: usize) -> Vec<Vec<Fraction>> {
+    (0..columns)
+        .filter(|column| !pivots.contains(column))
+        .map(|free_column| {
+            let mut vector = vec![Fraction::ZERO; columns];
+            vector[free_column] = Fraction::ONE;
+            for (row, &column) in pivots.iter().enumerate() {
+                vector[column] = -reduced[(row, free_column)];
+            }
+            vector
+        })
+        .collect()
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = Fraction;
+
+    /// Indexed by `(row, column)`.
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        &self.rows[row][column]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(rows: &[&[i64]]) -> Matrix {
+        Matrix::from_rows(
+            rows.iter()
+                .map(|row| row.iter().map(|&value| value.into()).collect())
+                .collect(),
+        )
+    }
+
+    fn vector(values: &[i64]) -> Vec<Fraction> {
+        values.iter().map(|&value| value.into()).collect()
+    }
+
+    #[test]
+    fn test_reduced_row_echelon() {
+        let (reduced, pivots) = matrix(&[&[0, 2, 4], &[1, 1, 1], &[2, 4, 6]]).reduced_row_echelon();
+        assert_eq!(reduced, matrix(&[&[1, 0, -1], &[0, 1, 2], &[0, 0, 0]]));
+        assert_eq!(pivots, vec![0, 1]);
+
+        assert_eq!(Matrix::identity(3).reduced_row_echelon().1, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_rank() {
+        assert_eq!(matrix(&[&[1, 2], &[3, 4]]).rank(), 2);
+        assert_eq!(matrix(&[&[1, 2], &[2, 4]]).rank(), 1);
+        assert_eq!(Matrix::zero(2, 3).rank(), 0);
+        assert_eq!(matrix(&[&[1, 2, 3]]).rank(), 1);
+    }
+
+    #[test]
+    fn test_null_space() {
+        let a = matrix(&[&[1, 2, 3], &[2, 4, 6]]);
+        let null_space = a.null_space();
+        assert_eq!(null_space.len(), 2);
+        for basis_vector in &null_space {
+            assert_eq!(a.mul_vector(basis_vector), vector(&[0, 0]));
+        }
+        assert!(matrix(&[&[1, 2], &[3, 4]]).null_space().is_empty());
+    }
+
+    #[test]
+    fn test_solve() {
+        let a = matrix(&[&[2, 1], &[1, 3]]);
+        assert_eq!(
+            a.solve(&vector(&[3, 5])),
+            Solutions::Unique(vec![Fraction::new(4, 5), Fraction::new(7, 5)])
+        );
+
+        let parallel = matrix(&[&[1, 2], &[2, 4]]);
+        assert_eq!(parallel.solve(&vector(&[3, 7])), Solutions::None);
+        let Solutions::Infinite {
+            particular,
+            null_space,
+        } = parallel.solve(&vector(&[3, 6]))
+        else {
+            panic!("the equations are multiples of each other");
+        };
+        assert_eq!(parallel.mul_vector(&particular), vector(&[3, 6]));
+        assert_eq!(null_space, vec![vector(&[-2, 1])]);
+    }
+
+    #[test]
+    fn test_solve_overdetermined() {
+        let a = matrix(&[&[1, 0], &[0, 1], &[1, 1]]);
+        assert_eq!(
+            a.solve(&vector(&[1, 2, 3])),
+            Solutions::Unique(vector(&[1, 2]))
+        );
+        assert_eq!(a.solve(&vector(&[1, 2, 4])), Solutions::None);
+    }
+}
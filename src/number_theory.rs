@@ -0,0 +1,228 @@
+macro_rules! impl_gcd {
+    ($($name:ident: $t:ty),*) => {
+        $(
+            /// The greatest common divisor, which is never negative. Only the divisor of two zeros
+            /// is zero.
+            pub fn $name(a: $t, b: $t) -> $t {
+                let (mut a, mut b) = (a.abs(), b.abs());
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            }
+        )*
+    };
+}
+
+impl_gcd!(gcd: i64, gcd_i128: i128);
+
+/// The least common multiple, which is never negative, or zero if either number is zero.
+pub fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        (a / gcd(a, b) * b).abs()
+    }
+}
+
+/// `a / b` rounded towards negative infinity.
+pub fn floor_div(a: i64, b: i64) -> i64 {
+    let quotient = a / b;
+    if a % b != 0 && (a < 0) != (b < 0) {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
+
+/// `a / b` rounded towards positive infinity.
+pub fn ceil_div(a: i64, b: i64) -> i64 {
+    -floor_div(-a, b)
+}
+
+/// `a / b` if `b` divides `a` exactly.
+pub fn exact_div(a: i64, b: i64) -> Option<i64> {
+    (b != 0 && a % b == 0).then(|| a / b)
+}
+
+/// The extended Euclidean algorithm: `(g, x, y)` with `g = gcd(a, b)` and `a·x + b·y = g`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a·x ≡ 1 (mod modulus)`, which exists if `a` and `modulus` are
+/// coprime.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    assert!(modulus > 0, "the modulus must be positive");
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// The Chinese remainder theorem: combines congruences `x ≡ residue (mod modulus)` into a single
+/// `(residue, modulus)`, with the residue in `0..modulus`. The moduli need not be coprime; if the
+/// congruences contradict each other, there is no solution.
+pub fn chinese_remainder(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    congruences.iter().try_fold(
+        (0, 1),
+        |(residue, modulus), &(other_residue, other_modulus)| {
+            assert!(other_modulus > 0, "the moduli must be positive");
+            // Find k with residue + k·modulus ≡ other_residue (mod other_modulus).
+            let (g, inverse, _) = extended_gcd(modulus, other_modulus);
+            let difference = exact_div(other_residue - residue, g)?;
+            let step = other_modulus / g;
+            let k = (difference as i128 * inverse as i128).rem_euclid(step as i128);
+
+            let combined_modulus = modulus as i128 * step as i128;
+            let combined_residue =
+                (residue as i128 + k * modulus as i128).rem_euclid(combined_modulus);
+            Some((
+                i64::try_from(combined_residue).ok()?,
+                i64::try_from(combined_modulus).ok()?,
+            ))
+        },
+    )
+}
+
+/// All integer solutions `(x, y)` of `a·x + b·y = c`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LinearSolutions {
+    None,
+    /// `(x + k·step_x, y + k·step_y)` for every integer `k`.
+    Line {
+        x: i64,
+        y: i64,
+        step_x: i64,
+        step_y: i64,
+    },
+    /// Every pair, since `a`, `b` and `c` are all zero.
+    All,
+}
+
+/// Solves the linear Diophantine equation `a·x + b·y = c`. The line's particular solution comes
+/// from the extended Euclidean algorithm, and its steps are the smallest that keep it integral.
+pub fn solve_linear_diophantine(a: i64, b: i64, c: i64) -> LinearSolutions {
+    if a == 0 && b == 0 {
+        return if c == 0 {
+            LinearSolutions::All
+        } else {
+            LinearSolutions::None
+        };
+    }
+
+    let (g, x, y) = extended_gcd(a, b);
+    let Some(scale) = exact_div(c, g) else {
+        return LinearSolutions::None;
+    };
+    LinearSolutions::Line {
+        x: x * scale,
+        y: y * scale,
+        step_x: b / g,
+        step_y: -a / g,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, -7), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd_i128(-(1 << 100), 3 << 90), 1 << 90);
+        assert_eq!(lcm(4, -6), 12);
+        assert_eq!(lcm(0, 5), 0);
+    }
+
+    #[test]
+    fn test_rounding_division() {
+        assert_eq!(floor_div(7, 2), 3);
+        assert_eq!(floor_div(-7, 2), -4);
+        assert_eq!(floor_div(7, -2), -4);
+        assert_eq!(floor_div(-6, 2), -3);
+        assert_eq!(ceil_div(7, 2), 4);
+        assert_eq!(ceil_div(-7, 2), -3);
+        assert_eq!(exact_div(12, -4), Some(-3));
+        assert_eq!(exact_div(12, 5), None);
+        assert_eq!(exact_div(12, 0), None);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-240, 46), (0, 5), (7, 0), (17, -5)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g, "{a}·{x} + {b}·{y}");
+        }
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+    }
+
+    #[test]
+    fn test_chinese_remainder() {
+        assert_eq!(
+            chinese_remainder(&[(2, 3), (3, 5), (2, 7)]),
+            Some((23, 105))
+        );
+        assert_eq!(chinese_remainder(&[(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(chinese_remainder(&[(1, 4), (2, 6)]), None);
+        assert_eq!(chinese_remainder(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(chinese_remainder(&[]), Some((0, 1)));
+        assert_eq!(
+            chinese_remainder(&[(1, 1_000_000_007), (2, 998_244_353)]),
+            Some((993_328_913_953_302_350, 998_244_359_987_710_471))
+        );
+    }
+
+    #[test]
+    fn test_solve_linear_diophantine() {
+        let LinearSolutions::Line {
+            x,
+            y,
+            step_x,
+            step_y,
+        } = solve_linear_diophantine(6, 15, 9)
+        else {
+            panic!("6x + 15y = 9 has solutions");
+        };
+        assert_eq!((step_x, step_y), (5, -2));
+        for k in -3..=3 {
+            assert_eq!(6 * (x + k * step_x) + 15 * (y + k * step_y), 9);
+        }
+
+        assert_eq!(solve_linear_diophantine(6, 15, 10), LinearSolutions::None);
+        assert_eq!(solve_linear_diophantine(0, 0, 0), LinearSolutions::All);
+        assert_eq!(solve_linear_diophantine(0, 0, 1), LinearSolutions::None);
+        assert_eq!(
+            solve_linear_diophantine(0, 4, 8),
+            LinearSolutions::Line {
+                x: 0,
+                y: 2,
+                step_x: 1,
+                step_y: 0
+            }
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Malformed puzzle input, pointing at the offending position.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn in_day(self, day: u8) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "`{}`", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A slice of the puzzle input that knows its line and column, so that errors can point into it.
+/// Lines and columns are 1-based, columns count characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    pub fn error(&self, expected: &str) -> ParseError {
+        ParseError {
+            day: None,
+            line: self.line,
+            column: self.column,
+            expected: expected.to_string(),
+            found: self.text.lines().next().unwrap_or("").to_string(),
+        }
+    }
+
+    /// The part of this span between the byte offsets `start` and `end`.
+    pub fn slice(&self, start: usize, end: usize) -> Self {
+        let skipped = &self.text[..start];
+        let (line, column) = match skipped.rfind('\n') {
+            Some(last_line_break) => (
+                self.line + skipped.matches('\n').count(),
+                skipped[last_line_break + 1..].chars().count() + 1,
+            ),
+            None => (self.line, self.column + skipped.chars().count()),
+        };
+
+        Self {
+            text: &self.text[start..end],
+            line,
+            column,
+        }
+    }
+
+    /// Converts a subslice of this span's text back into a span.
+    fn sub_span(&self, part: &'a str) -> Self {
+        let start = part.as_ptr() as usize - self.text.as_ptr() as usize;
+        self.slice(start, start + part.len())
+    }
+
+    /// The empty span right after the end of this span.
+    pub fn end(&self) -> Self {
+        self.slice(self.text.len(), self.text.len())
+    }
+
+    pub fn trim(&self) -> Self {
+        self.sub_span(self.text.trim())
+    }
+
+    /// Like [`str::lines`], so both `\n` and `\r\n` line endings are accepted.
+    pub fn lines(self) -> impl Iterator<Item = Span<'a>> {
+        self.text.lines().map(move |line| self.sub_span(line))
+    }
+
+    /// Groups of consecutive lines that are separated by blank lines.
+    pub fn blocks(&self) -> Vec<Span<'a>> {
+        let mut blocks = Vec::new();
+        let mut block: Option<(Span<'a>, Span<'a>)> = None;
+
+        for line in self.lines() {
+            if line.is_empty() {
+                blocks.extend(block.take().map(|(first, last)| self.between(first, last)));
+            } else {
+                block = Some((block.map(|(first, _)| first).unwrap_or(line), line));
+            }
+        }
+        blocks.extend(block.map(|(first, last)| self.between(first, last)));
+
+        blocks
+    }
+
+    fn between(&self, first: Span<'a>, last: Span<'a>) -> Self {
+        let start = first.text.as_ptr() as usize - self.text.as_ptr() as usize;
+        let end = last.text.as_ptr() as usize + last.text.len() - self.text.as_ptr() as usize;
+        self.slice(start, end)
+    }
+
+    pub fn split(self, separator: char) -> impl Iterator<Item = Span<'a>> {
+        self.text
+            .split(separator)
+            .map(move |part| self.sub_span(part))
+    }
+
+    pub fn split_whitespace(self) -> impl Iterator<Item = Span<'a>> {
+        self.text
+            .split_whitespace()
+            .map(move |part| self.sub_span(part))
+    }
+
+    pub fn split_once(
+        &self,
+        separator: &str,
+        expected: &str,
+    ) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        self.text
+            .find(separator)
+            .map(|start| {
+                (
+                    self.slice(0, start),
+                    self.slice(start + separator.len(), self.text.len()),
+                )
+            })
+            .ok_or_else(|| self.error(expected))
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>, ParseError> {
+        if self.text.starts_with(prefix) {
+            Ok(self.slice(prefix.len(), self.text.len()))
+        } else {
+            Err(self.error(&format!("`{prefix}`")))
+        }
+    }
+
+    pub fn chars(self) -> impl Iterator<Item = (char, Span<'a>)> {
+        self.text
+            .char_indices()
+            .map(move |(start, c)| (c, self.slice(start, start + c.len_utf8())))
+    }
+
+    pub fn parse<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        self.text.parse().map_err(|_| self.error(expected))
+    }
+}
+
+impl<'a> From<&'a str> for Span<'a> {
+    fn from(text: &'a str) -> Self {
+        Self::new(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_keep_positions() {
+        let span = Span::new("ab\r\ncd\n\nef\n");
+        let lines = span
+            .lines()
+            .map(|line| (line.text(), line.line(), line.column()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![("ab", 1, 1), ("cd", 2, 1), ("", 3, 1), ("ef", 4, 1)]
+        );
+    }
+
+    #[test]
+    fn test_split_keeps_columns() {
+        let line = Span::new("12 345\n6   7").lines().nth(1).unwrap();
+        let parts = line
+            .split_whitespace()
+            .map(|part| (part.text(), part.line(), part.column()))
+            .collect::<Vec<_>>();
+        assert_eq!(parts, vec![("6", 2, 1), ("7", 2, 5)]);
+
+        let (left, right) = Span::new("a|bc").split_once("|", "a `|`").unwrap();
+        assert_eq!((left.text(), left.column()), ("a", 1));
+        assert_eq!((right.text(), right.column()), ("bc", 3));
+    }
+
+    #[test]
+    fn test_blocks() {
+        let span = Span::new("a\nb\n\n\nc\r\n\r\nd\n");
+        let blocks = span
+            .blocks()
+            .iter()
+            .map(|block| (block.text(), block.line()))
+            .collect::<Vec<_>>();
+        assert_eq!(blocks, vec![("a\nb", 1), ("c", 5), ("d", 7)]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let line = Span::new("1\n2 x").lines().nth(1).unwrap();
+        let error = line
+            .split_whitespace()
+            .map(|part| part.parse::<u32>("a number"))
+            .collect::<Result<Vec<u32>, ParseError>>()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                day: None,
+                line: 2,
+                column: 3,
+                expected: "a number".to_string(),
+                found: "x".to_string()
+            }
+        );
+        assert_eq!(
+            error.in_day(4).to_string(),
+            "day 4, line 2, column 3: expected a number, found `x`"
+        );
+    }
+
+    #[test]
+    fn test_error_at_end_of_line() {
+        let line = Span::new("1   ");
+        assert_eq!(
+            line.split_once("   ", "two numbers")
+                .unwrap()
+                .1
+                .parse::<u32>("a number")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 5: expected a number, found end of line"
+        );
+        assert_eq!(
+            line.strip_prefix("2").unwrap_err().to_string(),
+            "line 1, column 1: expected `2`, found `1   `"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::solution::{Answer, Measured, Part};
+use crate::timing::format_duration;
+
+/// How `aoc run` prints its results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable lines per day.
+    #[default]
+    Text,
+    /// A JSON array with one record per part.
+    Json,
+    /// One tab-separated record per part.
+    Plain,
+}
+
+impl Format {
+    pub fn from_argument(argument: &str) -> Option<Self> {
+        match argument {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "plain" => Some(Self::Plain),
+            _ => None,
+        }
+    }
+}
+
+/// The answer of one part, if the input has one, together with how long it took (the median over
+/// all runs).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub elapsed: Duration,
+}
+
+/// One record per part the day has.
+pub fn records(day: u8, measured: &Measured) -> Vec<Record> {
+    let (part_one, part_two) = measured.answers.clone();
+    let mut records = vec![Record {
+        day,
+        part: Part::One,
+        answer: part_one,
+        elapsed: measured.timings.part_one.median(),
+    }];
+    if let Some(samples) = &measured.timings.part_two {
+        records.push(Record {
+            day,
+            part: Part::Two,
+            answer: part_two,
+            elapsed: samples.median(),
+        });
+    }
+    records
+}
+
+fn text_answer(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(answer) => answer.to_string(),
+        None => "no answer".to_string(),
+    }
+}
+
+pub fn format_text(day: u8, measured: &Measured) -> String {
+    let timings = &measured.timings;
+    let (part_one, part_two) = &measured.answers;
+    let mut text = format!(
+        "Day {day} (parsed in {})\n  Part one: {} ({})\n",
+        format_duration(timings.parse.median()),
+        text_answer(part_one),
+        format_duration(timings.part_one.median()),
+    );
+    if let Some(samples) = &timings.part_two {
+        writeln!(
+            text,
+            "  Part two: {} ({})",
+            text_answer(part_two),
+            format_duration(samples.median())
+        )
+        .unwrap();
+        for note in &measured.notes {
+            writeln!(text, "    {note}").unwrap();
+        }
+    }
+    text
+}
+
+/// Tab-separated `day`, `part`, `answer type`, `answer` and `elapsed nanoseconds`, one line per
+/// record. A part without an answer has `none` as its type and `-` as its answer.
+pub fn format_plain(records: &[Record]) -> String {
+    records
+        .iter()
+        .map(|record| {
+            format!(
+                "{}\t{}\t{}\t{}\t{}\n",
+                record.day,
+                record.part,
+                record.answer.as_ref().map_or("none", Answer::type_name),
+                record
+                    .answer
+                    .as_ref()
+                    .map_or_else(|| "-".to_string(), Answer::to_string),
+                record.elapsed.as_nanos()
+            )
+        })
+        .collect()
+}
+
+fn json_string(text: &str) -> String {
+    let mut json = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn json_answer(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(Answer::Integer(value)) => value.to_string(),
+        Some(Answer::Text(text)) => json_string(text),
+        None => "null".to_string(),
+    }
+}
+
+/// A JSON array with one record per line and a fixed key order, so that outputs diff cleanly.
+pub fn format_json(records: &[Record]) -> String {
+    let lines = records
+        .iter()
+        .map(|record| {
+            format!(
+                "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"answer_type\": {}, \"elapsed_ns\": {}}}",
+                record.day,
+                record.part,
+                json_answer(&record.answer),
+                record
+                    .answer
+                    .as_ref()
+                    .map_or_else(|| "null".to_string(), |answer| json_string(answer.type_name())),
+                record.elapsed.as_nanos()
+            )
+        })
+        .collect::<Vec<String>>();
+
+    if lines.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", lines.join(",\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timing::{Samples, Timings};
+
+    fn example_records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 1,
+                part: Part::One,
+                answer: Some(Answer::Integer(11)),
+                elapsed: Duration::from_nanos(1_500),
+            },
+            Record {
+                day: 1,
+                part: Part::Two,
+                answer: Some(Answer::Integer(-31)),
+                elapsed: Duration::from_micros(20),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_format_plain() {
+        assert_eq!(
+            format_plain(&example_records()),
+            "1\t1\tinteger\t11\t1500\n1\t2\tinteger\t-31\t20000\n"
+        );
+    }
+
+    #[test]
+    fn test_format_json() {
+        assert_eq!(
+            format_json(&example_records()),
+            "[\n\
+             \x20 {\"day\": 1, \"part\": 1, \"answer\": 11, \"answer_type\": \"integer\", \"elapsed_ns\": 1500},\n\
+             \x20 {\"day\": 1, \"part\": 2, \"answer\": -31, \"answer_type\": \"integer\", \"elapsed_ns\": 20000}\n\
+             ]\n"
+        );
+        assert_eq!(format_json(&[]), "[]\n");
+
+        let text_record = Record {
+            day: 17,
+            part: Part::One,
+            answer: Some(Answer::from("4,6,3")),
+            elapsed: Duration::from_nanos(900),
+        };
+        assert_eq!(
+            format_json(&[text_record]),
+            "[\n\
+             \x20 {\"day\": 17, \"part\": 1, \"answer\": \"4,6,3\", \"answer_type\": \"text\", \"elapsed_ns\": 900}\n\
+             ]\n"
+        );
+    }
+
+    #[test]
+    fn test_format_record_without_answer() {
+        let records = [Record {
+            day: 18,
+            part: Part::Two,
+            answer: None,
+            elapsed: Duration::from_nanos(700),
+        }];
+        assert_eq!(format_plain(&records), "18\t2\tnone\t-\t700\n");
+        assert_eq!(
+            format_json(&records),
+            "[\n\
+             \x20 {\"day\": 18, \"part\": 2, \"answer\": null, \"answer_type\": null, \"elapsed_ns\": 700}\n\
+             ]\n"
+        );
+    }
+
+    #[test]
+    fn test_format_text_with_notes() {
+        let samples = |nanos| Samples::new(vec![Duration::from_nanos(nanos)]);
+        let measured = Measured {
+            answers: (Some(Answer::Integer(4)), Some(Answer::from("a02,z02"))),
+            timings: Timings {
+                parse: samples(100),
+                part_one: samples(200),
+                part_two: Some(samples(300)),
+            },
+            notes: vec!["`x02 AND y02 -> z02` breaks a rule".to_string()],
+        };
+        assert_eq!(
+            format_text(24, &measured),
+            "Day 24 (parsed in 100ns)\n  Part one: 4 (200ns)\n  Part two: a02,z02 (300ns)\n    \
+             `x02 AND y02 -> z02` breaks a rule\n"
+        );
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a\"b\\c\n\t"), "\"a\\\"b\\\\c\\n\\u0009\"");
+    }
+
+    #[test]
+    fn test_format_from_argument() {
+        assert_eq!(Format::from_argument("json"), Some(Format::Json));
+        assert_eq!(Format::from_argument("plain"), Some(Format::Plain));
+        assert_eq!(Format::from_argument("yaml"), None);
+    }
+}
@@ -0,0 +1,47 @@
+use std::fmt;
+
+use crate::input::{load_input, InputError, InputSource};
+use crate::parse::ParseError;
+use crate::solution::{Day, DayAnswers, Measured};
+
+#[derive(Debug)]
+pub enum RunError {
+    Input(InputError),
+    Parse(ParseError),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Input(error) => write!(f, "{error}"),
+            Self::Parse(error) => write!(f, "invalid puzzle input at {error}"),
+        }
+    }
+}
+
+impl From<InputError> for RunError {
+    fn from(error: InputError) -> Self {
+        Self::Input(error)
+    }
+}
+
+impl From<ParseError> for RunError {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+pub fn solve_day(day: &Day, input_source: &InputSource) -> Result<DayAnswers, RunError> {
+    let input = load_input(day.number, input_source)?;
+    Ok(day.solve(&input)?)
+}
+
+/// Solves a day, running each phase `repetitions` times to time it.
+pub fn measure_day(
+    day: &Day,
+    input_source: &InputSource,
+    repetitions: usize,
+) -> Result<Measured, RunError> {
+    let input = load_input(day.number, input_source)?;
+    Ok(day.measure(&input, repetitions)?)
+}
@@ -0,0 +1,396 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The outcome of a search over an implicit graph: the lowest cost of every state the search
+/// settled, every predecessor that reaches a state at that cost, and the goals it reached.
+#[derive(Clone, Debug)]
+pub struct Search<S, C> {
+    costs: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    /// The lowest cost of reaching `state`, or none if the search did not settle it.
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    pub fn costs(&self) -> &HashMap<S, C> {
+        &self.costs
+    }
+
+    /// Every state that reaches `state` with its lowest cost, or none for a start state.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// The goal states reached at the lowest cost, in the order they were settled.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// The lowest cost of reaching any goal, or none if no goal is reachable.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goals.first().map(|goal| self.costs[goal])
+    }
+
+    /// One lowest-cost path from a start state to `target`, both included.
+    pub fn path(&self, target: &S) -> Option<Vec<S>> {
+        if !self.costs.contains_key(target) {
+            return None;
+        }
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors(&path[path.len() - 1]).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The number of distinct lowest-cost paths from a start state to `target`.
+    pub fn count_paths(&self, target: &S) -> usize {
+        fn count<S: Clone + Eq + Hash, C: Copy>(
+            search: &Search<S, C>,
+            state: &S,
+            counts: &mut HashMap<S, usize>,
+        ) -> usize {
+            if let Some(&known) = counts.get(state) {
+                return known;
+            }
+            let predecessors = search.predecessors(state);
+            let paths = if predecessors.is_empty() {
+                1
+            } else {
+                predecessors
+                    .iter()
+                    .map(|previous| count(search, previous, counts))
+                    .sum()
+            };
+            counts.insert(state.clone(), paths);
+            paths
+        }
+
+        if self.costs.contains_key(target) {
+            count(self, target, &mut HashMap::new())
+        } else {
+            0
+        }
+    }
+
+    /// Every state on any lowest-cost path from a start state to one of `targets`.
+    pub fn states_on_paths<'a>(&self, targets: impl IntoIterator<Item = &'a S>) -> HashSet<S>
+    where
+        S: 'a,
+    {
+        let mut stack = targets
+            .into_iter()
+            .filter(|target| self.costs.contains_key(target))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut states = HashSet::new();
+        while let Some(state) = stack.pop() {
+            if !states.contains(&state) {
+                stack.extend(self.predecessors(&state).iter().cloned());
+                states.insert(state);
+            }
+        }
+        states
+    }
+}
+
+/// Breadth-first search where every step costs one. Stops once every state as close as the
+/// nearest goal is settled; with a goal that never matches, it explores all reachable states.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut costs = HashMap::new();
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut goals = Vec::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if costs.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let cost = costs[&state];
+        if goals.first().is_some_and(|goal| cost > costs[goal]) {
+            break;
+        }
+        if is_goal(&state) {
+            goals.push(state);
+            continue;
+        }
+
+        for next in neighbours(&state) {
+            match costs.get(&next) {
+                Some(&best) if best == cost + 1 => {
+                    predecessors.entry(next).or_default().push(state.clone());
+                }
+                Some(_) => {}
+                None => {
+                    costs.insert(next.clone(), cost + 1);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    // States found beyond the goals may be missing predecessors that were never expanded.
+    if let Some(&goal_cost) = goals.first().map(|goal| &costs[goal]) {
+        costs.retain(|_, cost| *cost <= goal_cost);
+        predecessors.retain(|state, _| costs.contains_key(state));
+    }
+    Search {
+        costs,
+        predecessors,
+        goals,
+    }
+}
+
+/// Dijkstra's algorithm for non-negative step costs. Stops once every state as cheap as the
+/// cheapest goal is settled; with a goal that never matches, it explores all reachable states.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    a_star(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search, which settles states in order of their cost plus the `heuristic` estimate of the
+/// cost left to a goal. The heuristic must be consistent: it may never drop by more than the cost
+/// of a step, and it must be zero at the goals. Otherwise this behaves like [`dijkstra`].
+pub fn a_star<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut costs = HashMap::new();
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut settled = HashSet::new();
+    let mut goals = Vec::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        costs.insert(start.clone(), C::default());
+        queue.push(Entry {
+            priority: heuristic(&start),
+            state: start,
+        });
+    }
+
+    while let Some(Entry { priority, state }) = queue.pop() {
+        if goals.first().is_some_and(|goal| priority > costs[goal]) {
+            break;
+        }
+        // The queue can hold a state several times; only its cheapest entry is expanded.
+        if !settled.insert(state.clone()) {
+            continue;
+        }
+        if is_goal(&state) {
+            goals.push(state);
+            continue;
+        }
+
+        let cost = costs[&state];
+        for (next, step_cost) in neighbours(&state) {
+            let next_cost = cost + step_cost;
+            match costs.get(&next) {
+                Some(&best) if next_cost > best => {}
+                Some(&best) if next_cost == best => {
+                    predecessors.entry(next).or_default().push(state.clone());
+                }
+                _ => {
+                    costs.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    queue.push(Entry {
+                        priority: next_cost + heuristic(&next),
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+
+    // Unsettled states only have a tentative cost.
+    costs.retain(|state, _| settled.contains(state));
+    predecessors.retain(|state, _| settled.contains(state));
+    Search {
+        costs,
+        predecessors,
+        goals,
+    }
+}
+
+/// A queued state, ordered so that the lowest priority comes out of the max-heap first.
+struct Entry<S, C> {
+    priority: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A weighted directed graph as `(from, to, cost)` edges.
+    const EDGES: [(char, char, u32); 7] = [
+        ('a', 'b', 1),
+        ('a', 'c', 4),
+        ('b', 'c', 2),
+        ('b', 'd', 3),
+        ('c', 'd', 1),
+        ('d', 'e', 3),
+        ('f', 'e', 1),
+    ];
+
+    fn weighted_neighbours(node: &char) -> Vec<(char, u32)> {
+        EDGES
+            .iter()
+            .filter(|(from, _, _)| from == node)
+            .map(|&(_, to, cost)| (to, cost))
+            .collect()
+    }
+
+    fn sorted(states: impl IntoIterator<Item = char>) -> Vec<char> {
+        let mut states = states.into_iter().collect::<Vec<_>>();
+        states.sort();
+        states
+    }
+
+    /// The positions of a `width` by `height` grid without any walls.
+    fn open_grid(width: i32, height: i32) -> impl Fn(&(i32, i32)) -> Vec<(i32, i32)> {
+        move |&(x, y)| {
+            [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..width).contains(&x) && (0..height).contains(&y))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs([(0, 0)], open_grid(3, 3), |&state| state == (2, 2));
+        assert_eq!(search.goal_cost(), Some(4));
+        assert_eq!(search.goals(), [(2, 2)]);
+        assert_eq!(search.cost(&(1, 1)), Some(2));
+        assert_eq!(search.predecessors(&(1, 1)).len(), 2);
+        assert_eq!(search.predecessors(&(0, 0)), []);
+
+        let path = search.path(&(2, 2)).unwrap();
+        assert_eq!(path.len(), 5);
+        assert_eq!((path[0], path[4]), ((0, 0), (2, 2)));
+        // Every position of the grid is on one of the six shortest paths.
+        assert_eq!(search.count_paths(&(2, 2)), 6);
+        assert_eq!(search.count_paths(&(0, 0)), 1);
+        assert_eq!(search.states_on_paths(search.goals()).len(), 9);
+
+        let everything = bfs([(0, 0)], open_grid(4, 2), |_| false);
+        assert_eq!(everything.costs().len(), 8);
+        assert_eq!(everything.goal_cost(), None);
+    }
+
+    #[test]
+    fn test_bfs_stops_after_the_goal_distance() {
+        let search = bfs([(0, 0)], open_grid(10, 1), |&(x, _)| x == 3);
+        assert_eq!(search.goal_cost(), Some(3));
+        assert_eq!(search.cost(&(4, 0)), None);
+        assert_eq!(search.path(&(5, 0)), None);
+        assert_eq!(search.count_paths(&(5, 0)), 0);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra(['a'], weighted_neighbours, |&node| node == 'e');
+        assert_eq!(search.goal_cost(), Some(7));
+        assert_eq!(search.path(&'e'), Some(vec!['a', 'b', 'd', 'e']));
+        // `d` costs 4 both straight from `b` and through `c`.
+        assert_eq!(
+            sorted(search.predecessors(&'d').iter().copied()),
+            ['b', 'c']
+        );
+        assert_eq!(
+            sorted(search.states_on_paths(['e'].iter())),
+            ['a', 'b', 'c', 'd', 'e']
+        );
+        assert_eq!(search.cost(&'f'), None);
+        assert_eq!(search.count_paths(&'e'), 2);
+
+        let unreachable = dijkstra(['a'], weighted_neighbours, |&node| node == 'f');
+        assert_eq!(unreachable.goal_cost(), None);
+        assert_eq!(unreachable.costs().len(), 5);
+    }
+
+    #[test]
+    fn test_dijkstra_with_several_starts_and_goals() {
+        let search = dijkstra(['a', 'f'], weighted_neighbours, |&node| {
+            matches!(node, 'c' | 'e')
+        });
+        assert_eq!(search.goal_cost(), Some(1));
+        assert_eq!(search.goals(), ['e']);
+        assert_eq!(search.path(&'e'), Some(vec!['f', 'e']));
+    }
+
+    #[test]
+    fn test_a_star() {
+        let goal = (7, 5);
+        let distance = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+        let neighbours = |state: &(i32, i32)| {
+            open_grid(8, 6)(state)
+                .into_iter()
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+
+        let search = a_star([(0, 0)], neighbours, distance, |&state| state == goal);
+        let reference = dijkstra([(0, 0)], neighbours, |&state| state == goal);
+        assert_eq!(search.goal_cost(), Some(12));
+        assert_eq!(reference.goal_cost(), Some(12));
+        assert_eq!(search.path(&goal).unwrap().len(), 13);
+        assert_eq!(
+            search.states_on_paths(search.goals()).len(),
+            reference.states_on_paths(reference.goals()).len()
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use std::fmt;
+
+use crate::parse::ParseError;
+use crate::timing::{self, Timings};
+
+/// The result of a single puzzle part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+}
+
+impl Answer {
+    /// The name of the variant, as reported in machine-readable output.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Integer(_) => "integer",
+            Self::Text(_) => "text",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Self::Integer(i64::try_from(value).expect("integer answers fit into an i64"))
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_integer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A named example input from the puzzle description, with the answers the description gives for it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub part_one: Option<&'static str>,
+    pub part_two: Option<&'static str>,
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part_one,
+            Part::Two => self.part_two,
+        }
+    }
+}
+
+/// A day of the advent calendar: parses its puzzle input once and solves both parts on it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    const EXAMPLES: &'static [Example] = &[];
+
+    /// Whether the day has a second puzzle. Day 25 has only one: its second star is awarded for
+    /// finishing all the other puzzles. Such days keep the default [`part_two`](Self::part_two).
+    const HAS_PART_TWO: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses one of the [`EXAMPLES`](Self::EXAMPLES). Puzzle descriptions sometimes use other
+    /// parameters for their examples than for the real puzzle, like a smaller area.
+    fn parse_example(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+
+    /// The answer, or `None` if the input has none, like a maze without a way out.
+    fn part_one(input: &Self::Input) -> Option<Answer>;
+
+    /// Only called if the day [has a part two](Self::HAS_PART_TWO). `None` if the input has no
+    /// answer.
+    fn part_two(_input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    /// Lines explaining how part two arrived at its answer, printed under it by `aoc run`.
+    fn part_two_notes(_input: &Self::Input) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// The answers to part one and two. A part has none if the input has no answer for it, and part
+/// two also if the day has no part two.
+pub type DayAnswers = (Option<Answer>, Option<Answer>);
+
+/// The answers of a day together with how long each phase took and the
+/// [notes on part two](Solution::part_two_notes).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Measured {
+    pub answers: DayAnswers,
+    pub timings: Timings,
+    pub notes: Vec<String>,
+}
+
+/// A type-erased [`Solution`] so that all days can live in one registry.
+pub struct Day {
+    pub number: u8,
+    pub examples: &'static [Example],
+    pub has_part_two: bool,
+    measure: fn(&str, usize) -> Result<Measured, ParseError>,
+    solve_example: fn(&str) -> Result<DayAnswers, ParseError>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            examples: S::EXAMPLES,
+            has_part_two: S::HAS_PART_TWO,
+            measure: measure::<S>,
+            solve_example: solve_example::<S>,
+        }
+    }
+
+    /// The parts the day has puzzles for.
+    pub fn parts(&self) -> &'static [Part] {
+        if self.has_part_two {
+            &Part::ALL
+        } else {
+            &[Part::One]
+        }
+    }
+
+    pub fn solve(&self, input: &str) -> Result<DayAnswers, ParseError> {
+        self.measure(input, 1).map(|measured| measured.answers)
+    }
+
+    /// Solves one of the day's [`examples`](Self::examples).
+    pub fn solve_example(&self, input: &str) -> Result<DayAnswers, ParseError> {
+        (self.solve_example)(input)
+    }
+
+    /// Solves the day, running parsing and each part `repetitions` times to time them.
+    pub fn measure(&self, input: &str, repetitions: usize) -> Result<Measured, ParseError> {
+        (self.measure)(input, repetitions)
+    }
+}
+
+fn solve_example<S: Solution>(input: &str) -> Result<DayAnswers, ParseError> {
+    let parsed_input = S::parse_example(input).map_err(|error| error.in_day(S::DAY))?;
+    let part_two = S::HAS_PART_TWO
+        .then(|| S::part_two(&parsed_input))
+        .flatten();
+    Ok((S::part_one(&parsed_input), part_two))
+}
+
+fn measure<S: Solution>(input: &str, repetitions: usize) -> Result<Measured, ParseError> {
+    let (parsed_input, parse) = timing::measure(repetitions, || S::parse(input));
+    let parsed_input = parsed_input.map_err(|error| error.in_day(S::DAY))?;
+    let (part_one, part_one_samples) = timing::measure(repetitions, || S::part_one(&parsed_input));
+    let (part_two, part_two_samples) = S::HAS_PART_TWO
+        .then(|| timing::measure(repetitions, || S::part_two(&parsed_input)))
+        .unzip();
+    let notes = if S::HAS_PART_TWO {
+        S::part_two_notes(&parsed_input)
+    } else {
+        Vec::new()
+    };
+
+    Ok(Measured {
+        answers: (part_one, part_two.flatten()),
+        timings: Timings {
+            parse,
+            part_one: part_one_samples,
+            part_two: part_two_samples,
+        },
+        notes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::Span;
+
+    struct Sums;
+
+    impl Solution for Sums {
+        const DAY: u8 = 0;
+
+        type Input = Vec<u32>;
+
+        const EXAMPLES: &'static [Example] = &[Example {
+            name: "example",
+            input: "1 2 3",
+            part_one: Some("6"),
+            part_two: None,
+        }];
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Span::new(input)
+                .split_whitespace()
+                .map(|n| n.parse("a number"))
+                .collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Option<Answer> {
+            Some(input.iter().sum::<u32>().into())
+        }
+
+        fn part_two(input: &Self::Input) -> Option<Answer> {
+            let product = input.iter().product::<u32>();
+            (product != 0).then(|| product.into())
+        }
+
+        fn part_two_notes(input: &Self::Input) -> Vec<String> {
+            input.iter().map(|n| format!("factor {n}")).collect()
+        }
+    }
+
+    #[test]
+    fn test_day_solve() {
+        let day = Day::new::<Sums>();
+        assert_eq!(day.number, 0);
+        assert_eq!(day.examples.len(), 1);
+        assert_eq!(day.examples[0].expected(Part::Two), None);
+        assert_eq!(
+            day.solve("1 2 3"),
+            Ok((Some(Answer::Integer(6)), Some(Answer::Integer(6))))
+        );
+        assert_eq!(day.solve("0 1"), Ok((Some(Answer::Integer(1)), None)));
+    }
+
+    #[test]
+    fn test_day_solve_with_invalid_input() {
+        let day = Day::new::<Sums>();
+        assert_eq!(
+            day.solve("1 two 3").unwrap_err().to_string(),
+            "day 0, line 1, column 3: expected a number, found `two`"
+        );
+    }
+
+    #[test]
+    fn test_day_measure() {
+        let measured = Day::new::<Sums>().measure("2 3", 3).unwrap();
+        assert_eq!(
+            measured.answers,
+            (Some(Answer::Integer(5)), Some(Answer::Integer(6)))
+        );
+        assert_eq!(measured.notes, ["factor 2", "factor 3"]);
+        assert!(measured
+            .timings
+            .phases()
+            .iter()
+            .all(|(_, samples)| samples.durations().len() == 3));
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(-12i64).to_string(), "-12");
+        assert_eq!(Answer::from(7usize).to_string(), "7");
+        assert_eq!(Answer::from("4,6,3").to_string(), "4,6,3");
+        assert_eq!(Answer::from("4,6,3").type_name(), "text");
+    }
+
+    #[test]
+    #[should_panic(expected = "fit into an i64")]
+    fn test_answer_from_too_large_integer() {
+        let _ = Answer::from(u64::MAX);
+    }
+}
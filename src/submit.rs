@@ -0,0 +1,500 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::client::{Client, ClientError};
+use crate::config::Config;
+use crate::fetch::YEAR;
+use crate::parse::{ParseError, Span};
+use crate::solution::{Answer, Part};
+
+/// What the website said about a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    RateLimited(Option<Duration>),
+    /// The part was already solved, or part one is still open when submitting part two.
+    WrongLevel,
+    Unrecognized(String),
+}
+
+impl Verdict {
+    fn key(&self) -> Option<&'static str> {
+        match self {
+            Self::Correct => Some("correct"),
+            Self::TooHigh => Some("too_high"),
+            Self::TooLow => Some("too_low"),
+            Self::Wrong => Some("wrong"),
+            _ => None,
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "correct" => Some(Self::Correct),
+            "too_high" => Some(Self::TooHigh),
+            "too_low" => Some(Self::TooLow),
+            "wrong" => Some(Self::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "that's the right answer"),
+            Self::TooHigh => write!(f, "wrong, the answer is too high"),
+            Self::TooLow => write!(f, "wrong, the answer is too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::RateLimited(Some(wait)) => {
+                write!(f, "answered too recently, wait {}s", wait.as_secs())
+            }
+            Self::RateLimited(None) => write!(f, "answered too recently, wait a bit"),
+            Self::WrongLevel => write!(f, "this part is already solved or not unlocked yet"),
+            Self::Unrecognized(text) => write!(f, "unrecognized response: {text}"),
+        }
+    }
+}
+
+/// Drops HTML tags and collapses whitespace.
+fn text_content(html: &str) -> String {
+    let main = match (html.find("<main>"), html.find("</main>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in main.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => {
+                in_tag = false;
+                text.push(' ');
+            }
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses waits like `1m 23s` or `45s` out of "You have 1m 23s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let before = &text[..text.find(" left to wait")?];
+    let start = before.rfind("You have ")? + "You have ".len();
+    before[start..]
+        .split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number = number.parse::<u64>().ok()?;
+            match unit {
+                "h" => Some(number * 60 * 60),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+pub fn parse_response(html: &str) -> Verdict {
+    let text = text_content(html);
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::RateLimited(parse_wait(&text))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unrecognized(text.chars().take(200).collect())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Why an answer is not submitted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    NotAnAnswer { answer: String },
+    AlreadySolved { answer: String },
+    AlreadyTried { verdict: Verdict },
+    NotBelow { too_high: i64 },
+    NotAbove { too_low: i64 },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAnAnswer { answer } => write!(f, "`{answer}` is not an answer"),
+            Self::AlreadySolved { answer } => {
+                write!(f, "this part was already solved with {answer}")
+            }
+            Self::AlreadyTried { verdict } => {
+                write!(f, "this answer was submitted before: {verdict}")
+            }
+            Self::NotBelow { too_high } => {
+                write!(f, "{too_high} was already too high, so this answer is too")
+            }
+            Self::NotAbove { too_low } => {
+                write!(f, "{too_low} was already too low, so this answer is too")
+            }
+        }
+    }
+}
+
+/// Every answer with a definite verdict that was submitted so far. Stored one attempt per line as
+/// tab-separated day, part, verdict and answer.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+/// Kept next to the fetched inputs, which git ignores as well.
+pub fn default_history_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("inputs")
+        .join("real")
+        .join("attempts.tsv")
+}
+
+impl History {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut attempts = Vec::new();
+
+        for line in Span::new(input).lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let fields = line.split('\t').collect::<Vec<_>>();
+            let [day, part, verdict, answer] = fields[..] else {
+                return Err(line.error("a day, part, verdict and answer separated by tabs"));
+            };
+            attempts.push(Attempt {
+                day: day.parse("a day number")?,
+                part: match part.text() {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    _ => return Err(part.error("a part number")),
+                },
+                verdict: Verdict::from_key(verdict.text())
+                    .ok_or_else(|| verdict.error("`correct`, `too_high`, `too_low` or `wrong`"))?,
+                answer: answer.text().to_string(),
+            });
+        }
+
+        Ok(Self { attempts })
+    }
+
+    pub fn attempts(&self, day: u8, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| attempt.day == day && attempt.part == part)
+    }
+
+    /// Refuses answers that are known to be wrong, directly or through a too high or too low one,
+    /// and text that cannot be an answer at all, like a blank one or a placeholder such as `none`.
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Result<(), Refusal> {
+        let answer_text = answer.to_string();
+        if let Answer::Text(text) = answer {
+            if text.is_empty()
+                || text.contains(char::is_whitespace)
+                || text.eq_ignore_ascii_case("none")
+            {
+                return Err(Refusal::NotAnAnswer {
+                    answer: text.clone(),
+                });
+            }
+        }
+        for attempt in self.attempts(day, part) {
+            if attempt.verdict == Verdict::Correct {
+                return Err(Refusal::AlreadySolved {
+                    answer: attempt.answer.clone(),
+                });
+            }
+            if attempt.answer == answer_text {
+                return Err(Refusal::AlreadyTried {
+                    verdict: attempt.verdict.clone(),
+                });
+            }
+        }
+
+        let Answer::Integer(value) = *answer else {
+            return Ok(());
+        };
+        let bounds = |verdict| {
+            self.attempts(day, part)
+                .filter(move |attempt| attempt.verdict == verdict)
+                .flat_map(|attempt| attempt.answer.parse::<i64>().ok())
+        };
+        if let Some(too_high) = bounds(Verdict::TooHigh).filter(|&high| value >= high).min() {
+            return Err(Refusal::NotBelow { too_high });
+        }
+        if let Some(too_low) = bounds(Verdict::TooLow).filter(|&low| value <= low).max() {
+            return Err(Refusal::NotAbove { too_low });
+        }
+        Ok(())
+    }
+
+    /// Remembers the attempt if the verdict is definite, i.e. it tells something about the answer.
+    pub fn record(&mut self, day: u8, part: Part, answer: &Answer, verdict: &Verdict) -> bool {
+        if verdict.key().is_none() {
+            return false;
+        }
+        self.attempts.push(Attempt {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict: verdict.clone(),
+        });
+        true
+    }
+
+    pub fn to_text(&self) -> String {
+        self.attempts
+            .iter()
+            .flat_map(|attempt| {
+                let verdict = attempt.verdict.key()?;
+                Some(format!(
+                    "{}\t{}\t{verdict}\t{}\n",
+                    attempt.day, attempt.part, attempt.answer
+                ))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    MissingSession,
+    Refused(Refusal),
+    Request(ClientError),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSession => write!(
+                f,
+                "no session token, set `AOC_SESSION` or `session` in the config file"
+            ),
+            Self::Refused(refusal) => write!(f, "not submitting: {refusal}"),
+            Self::Request(error) => write!(f, "could not submit the answer: {error}"),
+        }
+    }
+}
+
+impl From<ClientError> for SubmitError {
+    fn from(error: ClientError) -> Self {
+        Self::Request(error)
+    }
+}
+
+/// Submits an answer unless the history already rules it out, and records the verdict.
+pub fn submit_answer(
+    config: &Config,
+    history: &mut History,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Verdict, SubmitError> {
+    history
+        .check(day, part, answer)
+        .map_err(SubmitError::Refused)?;
+
+    let session = config.session.as_ref().ok_or(SubmitError::MissingSession)?;
+    let client = Client::new(&config.base_url, session);
+    let response = client.post_form(
+        &format!("/{YEAR}/day/{day}/answer"),
+        &[
+            ("level", &part.to_string()),
+            ("answer", &answer.to_string()),
+        ],
+    )?;
+
+    let verdict = parse_response(&response);
+    history.record(day, part, answer, &verdict);
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub_server::StubServer;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main>\n<article><p>{article}</p></article>\n</main></body></html>")
+    }
+
+    fn history() -> History {
+        History::parse(
+            "7\t1\ttoo_high\t5000\n7\t1\ttoo_low\t100\n7\t1\twrong\t3000\n8\t2\tcorrect\t34\n",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer!  You are <span>one gold star</span> closer."
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too high.  If you're stuck, ..."
+            )),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer.  If you're stuck, ...")),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href=\"/2024/day/7\">[Return to Day 7]</a>")),
+            Verdict::RateLimited(Some(Duration::from_secs(83)))
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You gave an answer too recently.  You have 45s left to wait."
+            )),
+            Verdict::RateLimited(Some(Duration::from_secs(45)))
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Verdict::WrongLevel
+        );
+        assert_eq!(
+            parse_response("<html>Something <b>else</b></html>"),
+            Verdict::Unrecognized("Something else".to_string())
+        );
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let history = history();
+        assert_eq!(History::parse(&history.to_text()), Ok(history));
+        assert_eq!(
+            History::parse("7\t3\twrong\t1").unwrap_err().to_string(),
+            "line 1, column 3: expected a part number, found `3`"
+        );
+        assert_eq!(
+            History::parse("7\t1\tmaybe\t1").unwrap_err().to_string(),
+            "line 1, column 5: expected `correct`, `too_high`, `too_low` or `wrong`, found `maybe`"
+        );
+    }
+
+    #[test]
+    fn test_history_check() {
+        let history = history();
+        let check = |day, part, answer: i64| history.check(day, part, &answer.into());
+
+        assert_eq!(check(7, Part::One, 4000), Ok(()));
+        assert_eq!(check(7, Part::Two, 5000), Ok(()));
+        assert_eq!(
+            check(7, Part::One, 3000),
+            Err(Refusal::AlreadyTried {
+                verdict: Verdict::Wrong
+            })
+        );
+        assert_eq!(
+            check(7, Part::One, 6000),
+            Err(Refusal::NotBelow { too_high: 5000 })
+        );
+        assert_eq!(
+            check(7, Part::One, 100),
+            Err(Refusal::AlreadyTried {
+                verdict: Verdict::TooLow
+            })
+        );
+        assert_eq!(
+            check(7, Part::One, 99),
+            Err(Refusal::NotAbove { too_low: 100 })
+        );
+        assert_eq!(history.check(7, Part::One, &"6,0,3".into()), Ok(()));
+        for placeholder in ["none", "", "no answer"] {
+            assert_eq!(
+                history.check(7, Part::One, &placeholder.into()),
+                Err(Refusal::NotAnAnswer {
+                    answer: placeholder.to_string()
+                })
+            );
+        }
+        assert_eq!(
+            check(8, Part::Two, 35),
+            Err(Refusal::AlreadySolved {
+                answer: "34".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let server = StubServer::serve(vec![
+            (
+                200,
+                &page("That's not the right answer; your answer is too low."),
+            ),
+            (
+                200,
+                &page("You gave an answer too recently.  You have 30s left to wait."),
+            ),
+        ]);
+        let config = Config {
+            session: Some("secret".to_string()),
+            base_url: server.base_url.clone(),
+        };
+        let mut history = History::default();
+
+        assert_eq!(
+            submit_answer(&config, &mut history, 7, Part::Two, &Answer::Integer(42)).unwrap(),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            submit_answer(&config, &mut history, 7, Part::Two, &Answer::Integer(50)).unwrap(),
+            Verdict::RateLimited(Some(Duration::from_secs(30)))
+        );
+        // Only definite verdicts are remembered, and they rule out further answers.
+        assert_eq!(history.attempts(7, Part::Two).count(), 1);
+        assert!(matches!(
+            submit_answer(&config, &mut history, 7, Part::Two, &Answer::Integer(40)),
+            Err(SubmitError::Refused(Refusal::NotAbove { too_low: 42 }))
+        ));
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2024/day/7/answer");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+        assert_eq!(requests[0].body, "level=2&answer=42");
+        assert_eq!(requests[1].body, "level=2&answer=50");
+    }
+}
@@ -0,0 +1,58 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// Lays out rows as plain-text columns separated by two spaces, one line per row.
+pub fn format_table<const N: usize>(
+    header: [&str; N],
+    alignments: [Align; N],
+    rows: &[[String; N]],
+) -> String {
+    let mut widths = header.map(|column| column.chars().count());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    [header.map(str::to_string)]
+        .iter()
+        .chain(rows)
+        .map(|row| {
+            let cells = row
+                .iter()
+                .zip(widths.iter().zip(alignments))
+                .map(|(cell, (width, alignment))| match alignment {
+                    Align::Left => format!("{cell:<width$}"),
+                    Align::Right => format!("{cell:>width$}"),
+                })
+                .collect::<Vec<String>>();
+            cells.join("  ").trim_end().to_string() + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_table() {
+        let rows = [
+            ["1".to_string(), "µs".to_string(), "x".to_string()],
+            ["12".to_string(), "abc".to_string(), "".to_string()],
+        ];
+        assert_eq!(
+            format_table(
+                ["N", "Unit", "Note"],
+                [Align::Right, Align::Left, Align::Left],
+                &rows
+            ),
+            " N  Unit  Note\n\
+             \x201  µs    x\n\
+             12  abc\n"
+        );
+    }
+}
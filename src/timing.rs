@@ -0,0 +1,237 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::solution::Part;
+use crate::table::{self, Align};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+/// The durations of one or more runs of the same phase, sorted from fastest to slowest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Samples {
+    durations: Vec<Duration>,
+}
+
+impl Samples {
+    pub fn new(mut durations: Vec<Duration>) -> Self {
+        assert!(!durations.is_empty(), "samples need at least one duration");
+        durations.sort();
+        Self { durations }
+    }
+
+    /// The measured durations, sorted from fastest to slowest.
+    pub fn durations(&self) -> &[Duration] {
+        &self.durations
+    }
+
+    pub fn min(&self) -> Duration {
+        self.durations[0]
+    }
+
+    /// The middle duration, or the mean of the two middle ones for an even number of runs.
+    pub fn median(&self) -> Duration {
+        let middle = self.durations.len() / 2;
+        if self.durations.len().is_multiple_of(2) {
+            (self.durations[middle - 1] + self.durations[middle]) / 2
+        } else {
+            self.durations[middle]
+        }
+    }
+
+    pub fn max(&self) -> Duration {
+        self.durations[self.durations.len() - 1]
+    }
+}
+
+/// Runs `phase` `repetitions` times (at least once) and returns the result of the last run.
+pub fn measure<T>(repetitions: usize, mut phase: impl FnMut() -> T) -> (T, Samples) {
+    let mut durations = Vec::with_capacity(repetitions.max(1));
+    loop {
+        let start = Instant::now();
+        let result = phase();
+        durations.push(start.elapsed());
+        if durations.len() >= repetitions {
+            return (result, Samples::new(durations));
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Samples,
+    pub part_one: Samples,
+    /// Missing for days without a part two.
+    pub part_two: Option<Samples>,
+}
+
+impl Timings {
+    pub fn phases(&self) -> Vec<(Phase, &Samples)> {
+        let mut phases = vec![
+            (Phase::Parse, &self.parse),
+            (Phase::Part(Part::One), &self.part_one),
+        ];
+        phases.extend(
+            self.part_two
+                .iter()
+                .map(|samples| (Phase::Part(Part::Two), samples)),
+        );
+        phases
+    }
+}
+
+/// Formats a duration with two decimals in the largest unit that keeps it above one.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos}ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+/// A table of all phases of the given days, most expensive (by median) first.
+pub fn format_table(timings: &[(u8, Timings)]) -> String {
+    let mut phases = timings
+        .iter()
+        .flat_map(|(day, timings)| {
+            timings
+                .phases()
+                .into_iter()
+                .map(|(phase, samples)| (*day, phase, samples))
+        })
+        .collect::<Vec<_>>();
+    phases.sort_by(
+        |(day, phase, samples), (other_day, other_phase, other_samples)| {
+            other_samples
+                .median()
+                .cmp(&samples.median())
+                .then((day, phase).cmp(&(other_day, other_phase)))
+        },
+    );
+
+    let rows = phases
+        .iter()
+        .map(|(day, phase, samples)| {
+            [
+                day.to_string(),
+                phase.to_string(),
+                samples.durations().len().to_string(),
+                format_duration(samples.min()),
+                format_duration(samples.median()),
+                format_duration(samples.max()),
+            ]
+        })
+        .collect::<Vec<[String; 6]>>();
+
+    table::format_table(
+        ["Day", "Phase", "Runs", "Min", "Median", "Max"],
+        [
+            Align::Right,
+            Align::Left,
+            Align::Right,
+            Align::Right,
+            Align::Right,
+            Align::Right,
+        ],
+        &rows,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples(micros: &[u64]) -> Samples {
+        Samples::new(micros.iter().map(|&m| Duration::from_micros(m)).collect())
+    }
+
+    #[test]
+    fn test_samples() {
+        let odd = samples(&[30, 10, 20]);
+        assert_eq!(odd.min(), Duration::from_micros(10));
+        assert_eq!(odd.median(), Duration::from_micros(20));
+        assert_eq!(odd.max(), Duration::from_micros(30));
+        assert_eq!(
+            samples(&[40, 10, 20, 30]).median(),
+            Duration::from_micros(25)
+        );
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut runs = 0;
+        let (result, samples) = measure(5, || {
+            runs += 1;
+            runs
+        });
+        assert_eq!((result, samples.durations().len()), (5, 5));
+        assert_eq!(measure(0, || ()).1.durations().len(), 1);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.35µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2_000)), "2.00s");
+    }
+
+    #[test]
+    fn test_format_table_sorts_by_median() {
+        let timings = [
+            (
+                6,
+                Timings {
+                    parse: samples(&[5]),
+                    part_one: samples(&[40]),
+                    part_two: Some(samples(&[900, 1_000, 3_000])),
+                },
+            ),
+            (
+                7,
+                Timings {
+                    parse: samples(&[5]),
+                    part_one: samples(&[50]),
+                    part_two: Some(samples(&[20])),
+                },
+            ),
+            (
+                25,
+                Timings {
+                    parse: samples(&[1]),
+                    part_one: samples(&[2]),
+                    part_two: None,
+                },
+            ),
+        ];
+        assert_eq!(
+            format_table(&timings),
+            "Day  Phase   Runs       Min   Median      Max\n\
+             \x20 6  part 2     3  900.00µs   1.00ms   3.00ms\n\
+             \x20 7  part 1     1   50.00µs  50.00µs  50.00µs\n\
+             \x20 6  part 1     1   40.00µs  40.00µs  40.00µs\n\
+             \x20 7  part 2     1   20.00µs  20.00µs  20.00µs\n\
+             \x20 6  parse      1    5.00µs   5.00µs   5.00µs\n\
+             \x20 7  parse      1    5.00µs   5.00µs   5.00µs\n\
+             \x2025  part 1     1    2.00µs   2.00µs   2.00µs\n\
+             \x2025  parse      1    1.00µs   1.00µs   1.00µs\n"
+        );
+    }
+}
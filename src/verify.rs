@@ -0,0 +1,280 @@
+use std::fmt;
+
+use crate::answers::Answers;
+use crate::solution::{Answer, DayAnswers, Example, Part};
+use crate::table::{self, Align};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Mismatch,
+    Missing,
+    New,
+    /// Neither the input nor the recorded answers have an answer for the part.
+    NoAnswer,
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Mismatch | Self::Missing)
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "ok"),
+            Self::Mismatch => write!(f, "MISMATCH"),
+            Self::Missing => write!(f, "MISSING"),
+            Self::New => write!(f, "new"),
+            Self::NoAnswer => write!(f, "no answer"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Verification {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub expected: Option<String>,
+    pub status: Status,
+}
+
+impl Verification {
+    pub fn new(day: u8, part: Part, answer: Option<Answer>, expected: Option<&str>) -> Self {
+        let status = match (&answer, expected) {
+            (Some(answer), Some(expected)) if answer.to_string() == expected => Status::Correct,
+            (Some(_), Some(_)) => Status::Mismatch,
+            (Some(_), None) => Status::New,
+            (None, Some(_)) => Status::Missing,
+            (None, None) => Status::NoAnswer,
+        };
+
+        Self {
+            day,
+            part,
+            answer,
+            expected: expected.map(str::to_string),
+            status,
+        }
+    }
+}
+
+/// Compares the answers a day produced (if it produced any) with the recorded ones, for each of
+/// the `parts` the day has. If the day failed to produce answers, all its parts are missing.
+pub fn verify_day(
+    day: u8,
+    parts: &[Part],
+    answers: Option<DayAnswers>,
+    recorded_answers: &Answers,
+) -> Vec<Verification> {
+    let solved = answers.is_some();
+    let (part_one, part_two) = answers.unzip();
+    [
+        (Part::One, part_one.flatten()),
+        (Part::Two, part_two.flatten()),
+    ]
+    .into_iter()
+    .filter(|(part, _)| parts.contains(part))
+    .map(|(part, answer)| {
+        let verification = Verification::new(day, part, answer, recorded_answers.get(day, part));
+        if solved {
+            verification
+        } else {
+            Verification {
+                status: Status::Missing,
+                ..verification
+            }
+        }
+    })
+    .collect()
+}
+
+pub fn format_table(verifications: &[Verification]) -> String {
+    let rows = verifications
+        .iter()
+        .map(|verification| {
+            [
+                verification.day.to_string(),
+                verification.part.to_string(),
+                verification.status.to_string(),
+                verification
+                    .answer
+                    .as_ref()
+                    .map(Answer::to_string)
+                    .unwrap_or_else(|| "-".to_string()),
+                verification
+                    .expected
+                    .clone()
+                    .unwrap_or_else(|| "-".to_string()),
+            ]
+        })
+        .collect::<Vec<[String; 5]>>();
+
+    table::format_table(
+        ["Day", "Part", "Status", "Answer", "Expected"],
+        [
+            Align::Right,
+            Align::Right,
+            Align::Left,
+            Align::Left,
+            Align::Left,
+        ],
+        &rows,
+    )
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExampleVerification {
+    pub example: &'static str,
+    pub verification: Verification,
+}
+
+/// Compares the answers a day produced for an example with the ones the puzzle description gives.
+/// Parts without a given answer are skipped.
+pub fn verify_example(
+    day: u8,
+    example: &Example,
+    answers: Option<DayAnswers>,
+) -> Vec<ExampleVerification> {
+    let (part_one, part_two) = answers.unzip();
+    [
+        (Part::One, part_one.flatten()),
+        (Part::Two, part_two.flatten()),
+    ]
+    .into_iter()
+    .filter_map(|(part, answer)| {
+        let expected = example.expected(part)?;
+        Some(ExampleVerification {
+            example: example.name,
+            verification: Verification::new(day, part, answer, Some(expected)),
+        })
+    })
+    .collect()
+}
+
+pub fn format_example_table(verifications: &[ExampleVerification]) -> String {
+    let rows = verifications
+        .iter()
+        .map(
+            |ExampleVerification {
+                 example,
+                 verification,
+             }| {
+                [
+                    verification.day.to_string(),
+                    example.to_string(),
+                    verification.part.to_string(),
+                    verification.status.to_string(),
+                    verification
+                        .answer
+                        .as_ref()
+                        .map(Answer::to_string)
+                        .unwrap_or_else(|| "-".to_string()),
+                    verification
+                        .expected
+                        .clone()
+                        .unwrap_or_else(|| "-".to_string()),
+                ]
+            },
+        )
+        .collect::<Vec<[String; 6]>>();
+
+    table::format_table(
+        ["Day", "Example", "Part", "Status", "Answer", "Expected"],
+        [
+            Align::Right,
+            Align::Left,
+            Align::Right,
+            Align::Left,
+            Align::Left,
+            Align::Left,
+        ],
+        &rows,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorded_answers() -> Answers {
+        Answers::parse("[day_1]\npart_one = 11\npart_two = 31\n[day_2]\npart_one = 2").unwrap()
+    }
+
+    #[test]
+    fn test_verify_day() {
+        let statuses = |day, answers| {
+            verify_day(day, &Part::ALL, answers, &recorded_answers())
+                .iter()
+                .map(|verification| verification.status)
+                .collect::<Vec<Status>>()
+        };
+
+        assert_eq!(
+            statuses(1, Some((Some(11.into()), Some(31.into())))),
+            vec![Status::Correct, Status::Correct]
+        );
+        assert_eq!(
+            statuses(1, Some((Some(11.into()), Some(30.into())))),
+            vec![Status::Correct, Status::Mismatch]
+        );
+        assert_eq!(
+            statuses(2, Some((Some(2.into()), Some(4.into())))),
+            vec![Status::Correct, Status::New]
+        );
+        assert_eq!(statuses(2, None), vec![Status::Missing, Status::Missing]);
+        assert_eq!(
+            statuses(2, Some((None, None))),
+            vec![Status::Missing, Status::NoAnswer]
+        );
+
+        let single_part = verify_day(2, &[Part::One], None, &recorded_answers());
+        assert_eq!(single_part.len(), 1);
+        assert_eq!(single_part[0].status, Status::Missing);
+    }
+
+    #[test]
+    fn test_format_table() {
+        let verifications = [
+            verify_day(
+                1,
+                &Part::ALL,
+                Some((Some(11.into()), Some(30.into()))),
+                &recorded_answers(),
+            ),
+            verify_day(2, &Part::ALL, None, &recorded_answers()),
+        ]
+        .concat();
+        assert_eq!(
+            format_table(&verifications),
+            "Day  Part  Status    Answer  Expected\n\
+             \x20 1     1  ok        11      11\n\
+             \x20 1     2  MISMATCH  30      31\n\
+             \x20 2     1  MISSING   -       2\n\
+             \x20 2     2  MISSING   -       -\n"
+        );
+    }
+
+    #[test]
+    fn test_verify_example() {
+        let example = Example {
+            name: "small",
+            input: "",
+            part_one: None,
+            part_two: Some("80"),
+        };
+        let verifications = [
+            verify_example(12, &example, Some((Some(140.into()), Some(81.into())))),
+            verify_example(12, &example, None),
+        ]
+        .concat();
+        assert_eq!(
+            format_example_table(&verifications),
+            "Day  Example  Part  Status    Answer  Expected\n\
+             \x2012  small       2  MISMATCH  81      80\n\
+             \x2012  small       2  MISSING   -       80\n"
+        );
+    }
+}